@@ -0,0 +1,159 @@
+//! Benchmarks of every `SanitizerExt` combinator on its own.
+
+use std::hint::black_box;
+
+use criterion::measurement::WallTime;
+use criterion::{criterion_group, criterion_main, BenchmarkGroup, Criterion};
+#[cfg(feature = "emoji")]
+use path_sanitizer::sanitizers::EmojiAction;
+#[cfg(feature = "normalization")]
+use path_sanitizer::sanitizers::Form;
+use path_sanitizer::sanitizers::{Categories, ExtensionAction};
+use path_sanitizer::{AsciiSet, Sanitizer, SanitizerExt};
+
+mod corpus;
+
+use corpus::{corpora, Corpus};
+
+const FORBIDDEN_SET: AsciiSet =
+    AsciiSet::from_chars(&['<', '>', ':', '"', '/', '\\', '|', '?', '*']);
+
+fn bench_sanitizer(
+    group: &mut BenchmarkGroup<'_, WallTime>,
+    name: &str,
+    sanitizer: impl Sanitizer + Clone,
+    corpus: &Corpus,
+) {
+    group.bench_function(name, |b| {
+        b.iter(|| {
+            for name in &corpus.names {
+                black_box(
+                    sanitizer
+                        .sanitize_ref(black_box(name).chars())
+                        .collect::<String>(),
+                );
+            }
+        });
+    });
+}
+
+fn combinators(c: &mut Criterion) {
+    for corpus in corpora() {
+        let mut group = c.benchmark_group(format!("combinators/{}", corpus.name));
+        group.throughput(corpus.throughput());
+
+        // the cost of collecting the characters without sanitizing them
+        bench_sanitizer(&mut group, "none", (), &corpus);
+
+        bench_sanitizer(
+            &mut group,
+            "replace",
+            ().replace((FORBIDDEN_SET, '_')),
+            &corpus,
+        );
+        bench_sanitizer(
+            &mut group,
+            "replace-str",
+            ().replace_str((FORBIDDEN_SET, "%")),
+            &corpus,
+        );
+        bench_sanitizer(
+            &mut group,
+            "replace-control",
+            ().replace_control::<'_'>(),
+            &corpus,
+        );
+        bench_sanitizer(
+            &mut group,
+            "replace-categories",
+            ().replace_categories::<'_'>(Categories::DEFAULT),
+            &corpus,
+        );
+        bench_sanitizer(
+            &mut group,
+            "replace-invisible",
+            ().replace_invisible::<'_'>(),
+            &corpus,
+        );
+        bench_sanitizer(
+            &mut group,
+            "replace-whitespace",
+            ().replace_whitespace::<' '>(),
+            &corpus,
+        );
+        bench_sanitizer(
+            &mut group,
+            "deduplicate",
+            ().deduplicate(char::is_whitespace),
+            &corpus,
+        );
+        bench_sanitizer(
+            &mut group,
+            "strip-prefix",
+            ().strip_prefix(char::is_whitespace),
+            &corpus,
+        );
+        bench_sanitizer(
+            &mut group,
+            "padding",
+            ().padding::<'_', 3>(["CON", "NUL", "desktop.ini"]),
+            &corpus,
+        );
+        bench_sanitizer(&mut group, "truncate", ().truncate(255), &corpus);
+        bench_sanitizer(
+            &mut group,
+            "truncate-utf16",
+            ().truncate_utf16(255),
+            &corpus,
+        );
+        bench_sanitizer(&mut group, "transliterate", ().transliterate("_"), &corpus);
+        bench_sanitizer(
+            &mut group,
+            "extension-policy",
+            ().extension_policy(["exe", "lnk", "scr"], ExtensionAction::Append(".txt")),
+            &corpus,
+        );
+        #[cfg(feature = "normalization")]
+        bench_sanitizer(
+            &mut group,
+            "normalize-nfc",
+            ().normalize(Form::Nfc),
+            &corpus,
+        );
+        #[cfg(feature = "normalization")]
+        bench_sanitizer(
+            &mut group,
+            "normalize-nfkd",
+            ().normalize(Form::Nfkd),
+            &corpus,
+        );
+        #[cfg(feature = "confusables")]
+        bench_sanitizer(&mut group, "skeleton", ().skeleton(), &corpus);
+        #[cfg(feature = "graphemes")]
+        bench_sanitizer(
+            &mut group,
+            "deduplicate-graphemes",
+            ().deduplicate_graphemes(|grapheme| grapheme == " "),
+            &corpus,
+        );
+        #[cfg(feature = "graphemes")]
+        bench_sanitizer(
+            &mut group,
+            "truncate-graphemes",
+            ().truncate_graphemes(255),
+            &corpus,
+        );
+        #[cfg(feature = "emoji")]
+        bench_sanitizer(
+            &mut group,
+            "emoji-policy",
+            ().emoji_policy(EmojiAction::ShortName),
+            &corpus,
+        );
+
+        group.finish();
+    }
+}
+
+criterion_group!(benches, combinators);
+criterion_main!(benches);
@@ -0,0 +1,118 @@
+//! The names, which the benchmarks sanitize.
+
+use criterion::Throughput;
+
+/// A named collection of names with similar properties.
+pub struct Corpus {
+    pub name: &'static str,
+    pub names: Vec<String>,
+}
+
+impl Corpus {
+    fn new(name: &'static str, names: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            name,
+            names: names.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// The throughput in UTF-8 bytes, when every name is sanitized once.
+    pub fn throughput(&self) -> Throughput {
+        let bytes = self.names.iter().map(String::len).sum::<usize>();
+        Throughput::Bytes(u64::try_from(bytes).unwrap())
+    }
+}
+
+/// Returns the corpora, which cover the common and the worst cases.
+pub fn corpora() -> Vec<Corpus> {
+    vec![ascii(), cjk(), long(), mostly_valid()]
+}
+
+/// Typical names of downloads, photos and documents, which are already valid.
+fn ascii() -> Corpus {
+    Corpus::new(
+        "ascii",
+        [
+            "IMG_20230401_123456.jpg",
+            "report-final-v2.docx",
+            "Cargo.toml",
+            "README.md",
+            "node_modules",
+            "invoice 2023-11.pdf",
+            "Screenshot 2024-01-15 at 10.42.17.png",
+            "main.rs",
+            "backup.tar.gz",
+            "Meeting Notes (draft).txt",
+            ".gitignore",
+            "DSC01234.ARW",
+            "song - artist - album.flac",
+            "setup_x64_v1.2.3.exe",
+            "data.csv",
+            "presentation_Q3.pptx",
+        ],
+    )
+}
+
+/// Chinese, Japanese and Korean names, where every character needs 3 bytes in UTF-8.
+fn cjk() -> Corpus {
+    Corpus::new(
+        "cjk",
+        [
+            "報告書_2023年度.pdf",
+            "東京の写真.jpg",
+            "会議の議事録.docx",
+            "회의록_최종.hwp",
+            "中文文件名.txt",
+            "プレゼンテーション資料.pptx",
+            "新しいフォルダー",
+            "사진 모음",
+            "学习笔记（第一章）.md",
+            "見積書・請求書.xlsx",
+            "音楽／アルバム.zip",
+            "설정 파일.conf",
+        ],
+    )
+}
+
+/// Names, which are far longer than the platforms allow and consist of characters,
+/// that make the sanitizers do the most work.
+fn long() -> Corpus {
+    Corpus::new(
+        "long",
+        [
+            "a".repeat(4096),
+            "?".repeat(4096),
+            "e\u{301}".repeat(1024),
+            format!("{}name.txt", " ".repeat(4096)),
+            "a.".repeat(2048),
+            "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}".repeat(256),
+            "\u{202E}\u{200B}".repeat(1024),
+            "漢字".repeat(1024),
+        ],
+    )
+}
+
+/// Names from users, which are mostly valid, but contain a single problem.
+fn mostly_valid() -> Corpus {
+    Corpus::new(
+        "mostly-valid",
+        [
+            "notes: meeting.txt",
+            "what?.md",
+            "CON",
+            "nul.txt",
+            " leading space.pdf",
+            "trailing dot.",
+            "a/b.png",
+            "tab\there.csv",
+            "bell\x07.log",
+            "cafe\u{301}.jpg",
+            "photo.jpg.exe",
+            "~$temp.docx",
+            "desktop.ini",
+            "quote\"d.txt",
+            "pipe|name.rs",
+            "star*.tar",
+        ],
+    )
+}
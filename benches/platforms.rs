@@ -0,0 +1,60 @@
+//! Benchmarks of `sanitize_filename` for every platform.
+
+use std::hint::black_box;
+
+use criterion::measurement::WallTime;
+use criterion::{criterion_group, criterion_main, BenchmarkGroup, Criterion};
+use path_sanitizer::platforms::{HfsPlus, Linux, OneDrive, Windows, Wsl};
+use path_sanitizer::{Platform, Sanitizer};
+
+mod corpus;
+
+use corpus::{corpora, Corpus};
+
+fn bench_platform(
+    group: &mut BenchmarkGroup<'_, WallTime>,
+    name: &str,
+    platform: impl Platform,
+    corpus: &Corpus,
+) {
+    group.bench_function(name, |b| {
+        b.iter(|| {
+            for name in &corpus.names {
+                black_box(
+                    platform
+                        .filename_sanitizer()
+                        .sanitize(black_box(name).chars())
+                        .collect::<String>(),
+                );
+            }
+        });
+    });
+}
+
+fn platforms(c: &mut Criterion) {
+    for corpus in corpora() {
+        let mut group = c.benchmark_group(format!("platforms/{}", corpus.name));
+        group.throughput(corpus.throughput());
+
+        bench_platform(&mut group, "linux", Linux::<'_'>::default(), &corpus);
+        bench_platform(
+            &mut group,
+            "windows",
+            Windows::<'_', '_'>::default(),
+            &corpus,
+        );
+        bench_platform(
+            &mut group,
+            "onedrive",
+            OneDrive::<'_', '_'>::default(),
+            &corpus,
+        );
+        bench_platform(&mut group, "hfs-plus", HfsPlus::<'_'>::default(), &corpus);
+        bench_platform(&mut group, "wsl", Wsl::<'_'>::default(), &corpus);
+
+        group.finish();
+    }
+}
+
+criterion_group!(benches, platforms);
+criterion_main!(benches);
@@ -0,0 +1,42 @@
+#![no_main]
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use path_sanitizer::sanitizers::RuntimePadder;
+use path_sanitizer_fuzz::sanitize;
+
+#[derive(Debug, Arbitrary)]
+struct Input<'a> {
+    name: &'a str,
+    reserved: [&'a str; 3],
+    padding: char,
+    insert_before: Option<char>,
+}
+
+fuzz_target!(|input: Input<'_>| {
+    let padder = RuntimePadder::new(
+        input.reserved.map(str::chars),
+        input.padding,
+        input.insert_before,
+    );
+    let output = sanitize(padder, input.name);
+
+    // a reserved name, which is followed by nothing or `insert_before`, must be padded,
+    // the empty string matches the start of the name
+    let is_reserved = input.reserved.iter().any(|reserved| {
+        input
+            .name
+            .strip_prefix(reserved)
+            .is_some_and(|rest| rest.is_empty() || rest.chars().next() == input.insert_before)
+    });
+    assert_eq!(is_reserved, output != input.name, "{output:?}");
+
+    // the input is only extended with padding characters
+    let mut name = input.name.chars().peekable();
+    for c in output.chars() {
+        if name.next_if_eq(&c).is_none() {
+            assert_eq!(c, input.padding, "{output:?}");
+        }
+    }
+    assert_eq!(name.next(), None, "{output:?}");
+});
@@ -0,0 +1,103 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use path_sanitizer::platforms::{
+    HfsPlus, Linux, OneDrive, RuntimeLinux, RuntimeOneDrive, RuntimeWindows, Windows, Wsl,
+};
+use path_sanitizer::sanitizers::REMOVE;
+use path_sanitizer::Platform;
+use path_sanitizer_fuzz::sanitize;
+use path_sanitizer_fuzz::validators::{
+    is_hfs_plus_valid, is_linux_valid, is_onedrive_valid, is_windows_valid, is_wsl_valid,
+};
+
+/// Checks, that sanitizing is idempotent and the sanitized names are valid.
+fn check<P: Platform>(platform: &P, name: &str, is_valid: impl Fn(&str, bool) -> bool) {
+    let filename = sanitize(platform.filename_sanitizer(), name);
+    assert_eq!(sanitize(platform.filename_sanitizer(), &filename), filename);
+    assert!(
+        is_valid(&filename, true),
+        "invalid filename {filename:?} for {name:?}"
+    );
+
+    let folder = sanitize(platform.folder_sanitizer(), name);
+    assert_eq!(sanitize(platform.folder_sanitizer(), &folder), folder);
+    assert!(
+        is_valid(&folder, false),
+        "invalid folder {folder:?} for {name:?}"
+    );
+}
+
+/// The replacement and padding chosen at runtime could be changed by sanitizing again,
+/// like a combining character, so only the validity is checked.
+fn check_runtime<P: Platform>(platform: &P, name: &str, is_valid: impl Fn(&str, bool) -> bool) {
+    let filename = sanitize(platform.filename_sanitizer(), name);
+    assert!(
+        is_valid(&filename, true),
+        "invalid filename {filename:?} for {name:?}"
+    );
+
+    let folder = sanitize(platform.folder_sanitizer(), name);
+    assert!(
+        is_valid(&folder, false),
+        "invalid folder {folder:?} for {name:?}"
+    );
+}
+
+fuzz_target!(|input: (&str, char, char)| {
+    let (name, replacement, padding) = input;
+
+    check(&Linux::<'_'>::default(), name, |name, _| {
+        is_linux_valid(name)
+    });
+    check(&Linux::<REMOVE>::default(), name, |name, _| {
+        is_linux_valid(name)
+    });
+    check(&Windows::<'_', '_'>::default(), name, |name, _| {
+        is_windows_valid(name)
+    });
+    check(&Windows::<REMOVE, '_'>::default(), name, |name, _| {
+        is_windows_valid(name)
+    });
+    check(&OneDrive::<'_', '_'>::default(), name, is_onedrive_valid);
+    check(&OneDrive::<REMOVE, '_'>::default(), name, is_onedrive_valid);
+    check(&HfsPlus::<'_'>::default(), name, |name, _| {
+        is_hfs_plus_valid(name)
+    });
+    check(&HfsPlus::<REMOVE>::default(), name, |name, _| {
+        is_hfs_plus_valid(name)
+    });
+
+    // the characters in the mapped private use range are replaced, so sanitizing the mapped
+    // characters again is not idempotent
+    let wsl = Wsl::<'_'>::default();
+    let filename = sanitize(wsl.filename_sanitizer(), name);
+    assert!(
+        is_wsl_valid(&filename),
+        "invalid filename {filename:?} for {name:?}"
+    );
+    let folder = sanitize(wsl.folder_sanitizer(), name);
+    assert!(
+        is_wsl_valid(&folder),
+        "invalid folder {folder:?} for {name:?}"
+    );
+
+    // the builders reject characters, which are not allowed by the platform
+    if let Ok(platform) = RuntimeLinux::builder().replacement(replacement).build() {
+        check_runtime(&platform, name, |name, _| is_linux_valid(name));
+    }
+    if let Ok(platform) = RuntimeWindows::builder()
+        .replacement(replacement)
+        .padding(padding)
+        .build()
+    {
+        check_runtime(&platform, name, |name, _| is_windows_valid(name));
+    }
+    if let Ok(platform) = RuntimeOneDrive::builder()
+        .replacement(replacement)
+        .padding(padding)
+        .build()
+    {
+        check_runtime(&platform, name, is_onedrive_valid);
+    }
+});
@@ -0,0 +1,117 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use path_sanitizer::platforms::{Windows, Wsl};
+use path_sanitizer::sanitizers::{
+    is_invisible, Categories, Control, EmojiAction, EmojiPolicy, ExtensionAction, ExtensionPolicy,
+    Form, GeneralCategory, GraphemeDeduplicator, GraphemeTruncate, Invisible, Normalize,
+    PrefixStripper, Replacer, Skeleton, Transliterate, Truncate, Unescaper, Whitespace, REMOVE,
+};
+use path_sanitizer::{AsciiSet, Platform, SanitizerExt};
+use path_sanitizer_fuzz::sanitize;
+
+const FORBIDDEN_SET: AsciiSet = AsciiSet::from_chars(&['<', '>']);
+
+fuzz_target!(|name: &str| {
+    let output = sanitize(Control::<'_'>::default(), name);
+    assert!(!output.chars().any(char::is_control), "{output:?}");
+    let output = sanitize(Control::<REMOVE>::default(), name);
+    assert!(!output.chars().any(char::is_control), "{output:?}");
+
+    let output = sanitize(Whitespace::<'_'>::default(), name);
+    assert!(!output.chars().any(char::is_whitespace), "{output:?}");
+
+    let output = sanitize(Invisible::<'_'>::default(), name);
+    assert!(!output.chars().any(is_invisible), "{output:?}");
+
+    let categories = Categories::DEFAULT | Categories::FORMAT | Categories::PRIVATE_USE;
+    let output = sanitize(GeneralCategory::<REMOVE>::new(categories), name);
+    assert!(!output.chars().any(|c| categories.matches(c)), "{output:?}");
+
+    let output = sanitize(Replacer::from((FORBIDDEN_SET, '_')), name);
+    assert!(!output.contains(['<', '>']), "{output:?}");
+    let output = sanitize(Replacer::from((FORBIDDEN_SET, Some('_'))), name);
+    assert!(!output.contains(['<', '>']), "{output:?}");
+    let output = sanitize(Replacer::from((FORBIDDEN_SET, "__")), name);
+    assert!(!output.contains(['<', '>']), "{output:?}");
+
+    // the private use mapping of WSL is reversible, except for the replaced characters
+    let wsl = Wsl::<'_'>::default();
+    let output = sanitize(wsl.filename_sanitizer(), name);
+    let expected = name
+        .chars()
+        .map(|c| {
+            let is_replaced = matches!(c, '/' | '\0' | '\u{F000}'..='\u{F07F}') || is_invisible(c);
+            if is_replaced {
+                '_'
+            } else {
+                c
+            }
+        })
+        .collect::<String>();
+    assert_eq!(sanitize(wsl.decoder(), &output), expected);
+
+    let output = sanitize(().deduplicate(|c| c == '_'), name);
+    assert!(!output.contains("__"), "{output:?}");
+
+    let output = sanitize(PrefixStripper::new(char::is_whitespace), name);
+    assert!(!output.starts_with(char::is_whitespace), "{output:?}");
+
+    let output = sanitize(Truncate::new(16), name);
+    assert!(
+        output.len() <= 16 && name.starts_with(&output),
+        "{output:?}"
+    );
+    let output = sanitize(Truncate::utf16(16), name);
+    assert!(
+        output.encode_utf16().count() <= 16 && name.starts_with(&output),
+        "{output:?}"
+    );
+    let output = sanitize(GraphemeTruncate::new(16), name);
+    assert!(
+        output.len() <= 16 && name.starts_with(&output),
+        "{output:?}"
+    );
+
+    sanitize(GraphemeDeduplicator::new(|grapheme| grapheme == "_"), name);
+
+    for form in [Form::Nfc, Form::Nfd, Form::Nfkc, Form::Nfkd] {
+        let output = sanitize(Normalize::new(form), name);
+        assert_eq!(sanitize(Normalize::new(form), &output), output);
+    }
+
+    // a prototype never introduces a path separator or a character forbidden on Windows
+    let forbidden = ['/', '\\', ':', '*', '?', '"', '<', '>', '|'];
+    let output = sanitize(Skeleton::default(), name);
+    assert!(
+        name.contains(forbidden) || !output.contains(forbidden),
+        "{output:?}"
+    );
+
+    let output = sanitize(Transliterate::new("_"), name);
+    assert!(output.is_ascii(), "{output:?}");
+
+    for action in [
+        ExtensionAction::Append(".txt"),
+        ExtensionAction::Replace("txt"),
+    ] {
+        let policy = ExtensionPolicy::new(["exe", "lnk"], action);
+        let output = sanitize(policy, name);
+        assert_eq!(policy.check(&output), None, "{output:?}");
+    }
+
+    for action in [
+        EmojiAction::Keep,
+        EmojiAction::Remove,
+        EmojiAction::Replace("_"),
+        EmojiAction::ShortName,
+    ] {
+        sanitize(EmojiPolicy::new(action), name);
+    }
+
+    // escaping is reversible
+    let windows = Windows::<'_', '_'>::default();
+    let output = sanitize(windows.escaper(), name);
+    assert_eq!(sanitize(windows.unescaper(), &output), name);
+    sanitize(Unescaper::default(), name);
+});
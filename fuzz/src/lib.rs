@@ -0,0 +1,41 @@
+//! Helpers shared by the fuzz targets, run them with `cargo fuzz run <target>`.
+
+use path_sanitizer::sanitizers::is_invisible;
+use path_sanitizer::Sanitizer;
+
+/// The same validators as the property tests of the platforms.
+#[path = "../../src/platforms/validators.rs"]
+pub mod validators;
+
+/// Sanitizes the input and checks, that the number of remaining characters
+/// was within the `size_hint` of the iterator before every call to `next`.
+pub fn sanitize<S: Sanitizer>(sanitizer: S, input: &str) -> String {
+    let mut iter = sanitizer.sanitize(input.chars());
+    let mut hints = Vec::new();
+    let mut output = String::new();
+
+    loop {
+        hints.push(iter.size_hint());
+
+        match iter.next() {
+            Some(c) => output.push(c),
+            None => break,
+        }
+    }
+
+    let len = hints.len() - 1;
+    for (consumed, (lower, upper)) in hints.into_iter().enumerate() {
+        let remaining = len - consumed;
+
+        assert!(
+            lower <= remaining,
+            "lower bound {lower} after {consumed} characters of {output:?} for {input:?}"
+        );
+        assert!(
+            upper.is_none_or(|upper| remaining <= upper),
+            "upper bound {upper:?} after {consumed} characters of {output:?} for {input:?}"
+        );
+    }
+
+    output
+}
@@ -0,0 +1,277 @@
+//! Sanitizers, which are described by a configuration file (like TOML or JSON)
+//! instead of being built in code, so the rules can be changed without recompiling.
+//!
+//! ```
+//! use path_sanitizer::config::PipelineConfig;
+//! use path_sanitizer::DynSanitizer;
+//!
+//! let config: PipelineConfig = serde_json::from_str(r#"{
+//!     "platform": "windows",
+//!     "replacement": "-",
+//!     "replace": { "&": "+" },
+//!     "strip-prefix": "_",
+//!     "deduplicate": "-",
+//!     "reserved-names": ["desktop.ini"]
+//! }"#).unwrap();
+//! let sanitizer = config.sanitizer().unwrap();
+//!
+//! assert_eq!(sanitizer.sanitize_str("_a&b--c?"), "a+b-c-");
+//! assert_eq!(sanitizer.sanitize_str("desktop.ini"), "desktop.ini_");
+//! ```
+
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use serde::Deserialize;
+
+use crate::platforms::{BuildError, RuntimeLinux, RuntimeOneDrive, RuntimeWindows};
+use crate::sanitizers::{
+    replacement_for, Deduplicator, PrefixStripper, Replacer, RuntimePadder, Truncate,
+};
+use crate::{DynSanitizer, Platform};
+
+/// The platform, whose rules are the base of a [`PipelineConfig`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BasePlatform {
+    Linux,
+    Windows,
+    OneDrive,
+}
+
+/// The description of a sanitizer for filenames, every field is optional.
+///
+/// The steps are applied in the order of the fields.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct PipelineConfig {
+    /// Characters, which are replaced before anything else, like `&` with `+`.
+    pub replace: BTreeMap<char, char>,
+    /// Replaces control characters like `\n` with the `replacement`.
+    pub control: bool,
+    /// Characters, which are replaced with the `replacement` in addition to the ones of the platform.
+    pub forbidden: String,
+    /// Characters, which are removed from the start of the name.
+    pub strip_prefix: String,
+    /// Characters, which are only kept once, if they appear multiple times in a row.
+    pub deduplicate: String,
+    /// Names, which are padded with the `padding` character, in addition to the ones of the platform.
+    pub reserved_names: Vec<String>,
+    /// The maximum length of the name in UTF-8 bytes, before the `reserved_names` are padded.
+    pub max_length: Option<usize>,
+    /// The platform, whose filename rules are applied after all other steps,
+    /// so those can not create a name, which is invalid on the platform.
+    pub platform: Option<BasePlatform>,
+    /// The replacement character, `"\u0000"` ([`REMOVE`](crate::sanitizers::REMOVE)) removes the characters instead.
+    pub replacement: char,
+    /// The character, which is appended to reserved names.
+    pub padding: char,
+}
+
+impl PipelineConfig {
+    /// Returns the sanitizer described by this configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `replacement` or `padding` character is not allowed by the `platform`,
+    /// like the replacement `/` on Linux.
+    pub fn sanitizer(&self) -> Result<Box<dyn DynSanitizer + '_>, BuildError> {
+        let replacement = replacement_for(self.replacement);
+        let mut sanitizers: Vec<Box<dyn DynSanitizer + '_>> = Vec::new();
+
+        if !self.replace.is_empty() {
+            sanitizers.push(Box::new(Replacer::from(self.replace.clone())));
+        }
+
+        if self.control {
+            sanitizers.push(Box::new(Replacer::from((
+                char::is_control as fn(char) -> bool,
+                replacement,
+            ))));
+        }
+
+        if !self.forbidden.is_empty() {
+            let forbidden = self
+                .forbidden
+                .chars()
+                .map(|c| (c, replacement))
+                .collect::<BTreeMap<_, _>>();
+            sanitizers.push(Box::new(Replacer::from(forbidden)));
+        }
+
+        if !self.strip_prefix.is_empty() {
+            let prefix = self.strip_prefix.as_str();
+            sanitizers.push(Box::new(PrefixStripper::new(move |c| prefix.contains(c))));
+        }
+
+        if !self.deduplicate.is_empty() {
+            let duplicates = self.deduplicate.as_str();
+            sanitizers.push(Box::new(Deduplicator::new(move |c| duplicates.contains(c))));
+        }
+
+        // truncating after the padders could cut the padding off again
+        if let Some(max_length) = self.max_length {
+            sanitizers.push(Box::new(Truncate::new(max_length)));
+        }
+
+        // every name has its own padder, because the number of names is only known at runtime
+        for name in &self.reserved_names {
+            sanitizers.push(Box::new(RuntimePadder::new(
+                [name.chars()],
+                self.padding,
+                None,
+            )));
+        }
+
+        match self.platform {
+            Some(BasePlatform::Linux) => {
+                let platform = RuntimeLinux::builder()
+                    .replacement(self.replacement)
+                    .build()?;
+                sanitizers.push(Box::new(platform.filename_sanitizer()));
+            }
+            Some(BasePlatform::Windows) => {
+                let platform = RuntimeWindows::builder()
+                    .replacement(self.replacement)
+                    .padding(self.padding)
+                    .build()?;
+                sanitizers.push(Box::new(platform.filename_sanitizer()));
+            }
+            Some(BasePlatform::OneDrive) => {
+                let platform = RuntimeOneDrive::builder()
+                    .replacement(self.replacement)
+                    .padding(self.padding)
+                    .build()?;
+                sanitizers.push(Box::new(platform.filename_sanitizer()));
+            }
+            None => {}
+        }
+
+        Ok(Box::new(sanitizers))
+    }
+}
+
+impl Default for PipelineConfig {
+    fn default() -> Self {
+        Self {
+            replace: BTreeMap::new(),
+            control: false,
+            forbidden: String::new(),
+            strip_prefix: String::new(),
+            deduplicate: String::new(),
+            reserved_names: Vec::new(),
+            max_length: None,
+            platform: None,
+            replacement: '\u{FFFD}',
+            padding: '_',
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_empty() {
+        let config: PipelineConfig = serde_json::from_str("{}").unwrap();
+
+        assert_eq!(config, PipelineConfig::default());
+        assert_eq!(config.sanitizer().unwrap().sanitize_str("a/b\0c"), "a/b\0c");
+    }
+
+    #[test]
+    fn test_linux() {
+        let config: PipelineConfig = serde_json::from_str(
+            r#"{
+                "platform": "linux",
+                "replacement": "_",
+                "control": true,
+                "forbidden": "\\:",
+                "max-length": 6
+            }"#,
+        )
+        .unwrap();
+        let sanitizer = config.sanitizer().unwrap();
+
+        assert_eq!(sanitizer.sanitize_str("a/b\\c:\nd"), "a_b_c_");
+        assert_eq!(sanitizer.sanitize_str("\u{202E}x"), "_x");
+    }
+
+    #[test]
+    fn test_remove() {
+        let config: PipelineConfig = serde_json::from_str(
+            r#"{
+                "platform": "onedrive",
+                "replacement": "\u0000",
+                "padding": "+",
+                "deduplicate": " ",
+                "reserved-names": ["thumbs.db", "CON"]
+            }"#,
+        )
+        .unwrap();
+        let sanitizer = config.sanitizer().unwrap();
+
+        assert_eq!(sanitizer.sanitize_str("a#b   c"), "ab c");
+        assert_eq!(sanitizer.sanitize_str("thumbs.db"), "thumbs.db+");
+        assert_eq!(sanitizer.sanitize_str("CON"), "CON+");
+    }
+
+    #[test]
+    fn test_reserved_after_truncate() {
+        let config: PipelineConfig = serde_json::from_str(
+            r#"{
+                "padding": "_",
+                "reserved-names": ["secret"],
+                "max-length": 6
+            }"#,
+        )
+        .unwrap();
+        let sanitizer = config.sanitizer().unwrap();
+
+        assert_eq!(sanitizer.sanitize_str("secretX"), "secret_");
+        assert_eq!(sanitizer.sanitize_str("secret"), "secret_");
+        assert_eq!(sanitizer.sanitize_str("secre"), "secre");
+    }
+
+    #[test]
+    fn test_unknown_field() {
+        let result = serde_json::from_str::<PipelineConfig>(r#"{ "platfrom": "linux" }"#);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_platform_last() {
+        let config: PipelineConfig = serde_json::from_str(
+            r#"{
+                "platform": "windows",
+                "replacement": "\u0000",
+                "forbidden": "!",
+                "strip-prefix": "x",
+                "max-length": 4
+            }"#,
+        )
+        .unwrap();
+        let sanitizer = config.sanitizer().unwrap();
+
+        assert_eq!(sanitizer.sanitize_str("CON!"), "CON_");
+        assert_eq!(sanitizer.sanitize_str("xCON"), "CON_");
+        assert_eq!(sanitizer.sanitize_str("abc. d"), "abc_");
+    }
+
+    #[test]
+    fn test_forbidden_replacement() {
+        let config: PipelineConfig =
+            serde_json::from_str(r#"{ "platform": "linux", "replacement": "/" }"#).unwrap();
+        assert_eq!(config.sanitizer().err(), Some(BuildError::Replacement('/')));
+
+        let config: PipelineConfig =
+            serde_json::from_str(r#"{ "platform": "windows", "padding": " " }"#).unwrap();
+        assert_eq!(config.sanitizer().err(), Some(BuildError::Padding(' ')));
+    }
+}
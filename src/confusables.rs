@@ -0,0 +1,106 @@
+//! Detection of names, which can be confused with other names,
+//! see <https://www.unicode.org/reports/tr39/>.
+use core::str::CharIndices;
+
+use unicode_script::{Script, UnicodeScript};
+use unicode_security::{is_potential_mixed_script_confusable_char, MixedScript};
+
+use crate::sanitizers::Skeleton;
+use crate::Sanitizer;
+
+/// Returns true if the name contains characters of more than one script,
+/// like `раypal`, where the first two characters are Cyrillic.
+#[must_use]
+pub fn is_mixed_script(name: &str) -> bool {
+    !name.is_single_script()
+}
+
+/// Returns true if both names have the same [`Skeleton`], so they look (almost) identical.
+#[must_use]
+pub fn is_confusable(a: &str, b: &str) -> bool {
+    Skeleton::default()
+        .sanitize(a.chars())
+        .eq(Skeleton::default().sanitize(b.chars()))
+}
+
+/// Returns the characters (and their byte index) of a mixed script name,
+/// which are not from the most common script of the name and can be confused
+/// with characters of another script.
+///
+/// For example in `раypal` the Cyrillic `р` and `а` are suspicious.
+#[must_use]
+pub fn suspicious_characters(name: &str) -> SuspiciousCharacters<'_> {
+    SuspiciousCharacters {
+        iter: name.char_indices(),
+        script: is_mixed_script(name).then(|| main_script(name)).flatten(),
+    }
+}
+
+#[must_use]
+const fn is_specific(script: Script) -> bool {
+    !matches!(script, Script::Common | Script::Inherited | Script::Unknown)
+}
+
+/// Returns the script, which is used by most characters of the name.
+#[must_use]
+fn main_script(name: &str) -> Option<Script> {
+    let count = |script| name.chars().filter(|c| c.script() == script).count();
+
+    name.chars()
+        .map(|c| c.script())
+        .filter(|script| is_specific(*script))
+        .max_by_key(|script| count(*script))
+}
+
+#[derive(Debug, Clone)]
+pub struct SuspiciousCharacters<'a> {
+    iter: CharIndices<'a>,
+    /// the main script of a mixed script name
+    script: Option<Script>,
+}
+
+impl Iterator for SuspiciousCharacters<'_> {
+    type Item = (usize, char);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let script = self.script?;
+
+        self.iter.find(|(_, c)| {
+            is_specific(c.script())
+                && c.script() != script
+                && is_potential_mixed_script_confusable_char(*c)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_mixed_script() {
+        assert!(is_mixed_script("\u{0440}\u{0430}ypal.pdf"));
+        assert!(!is_mixed_script("paypal.pdf"));
+        assert!(!is_mixed_script("\u{0410}\u{0444}\u{0438}\u{043D}\u{044B}"));
+    }
+
+    #[test]
+    fn test_confusable() {
+        assert!(is_confusable("\u{0440}\u{0430}ypal.pdf", "paypal.pdf"));
+        assert!(!is_confusable("paypal.pdf", "paypa1.doc"));
+    }
+
+    #[test]
+    fn test_suspicious_characters() {
+        let mut iter = suspicious_characters("\u{0440}\u{0430}ypal.pdf");
+
+        assert_eq!(iter.next(), Some((0, '\u{0440}')));
+        assert_eq!(iter.next(), Some((2, '\u{0430}')));
+        assert_eq!(iter.next(), None);
+
+        assert_eq!(suspicious_characters("paypal.pdf").next(), None);
+        assert_eq!(suspicious_characters("abc\u{65E5}\u{672C}").next(), None);
+    }
+}
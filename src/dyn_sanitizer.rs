@@ -0,0 +1,134 @@
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::sanitizer::Sanitizer;
+
+/// The iterator, which is passed to and returned by a [`DynSanitizer`].
+pub type DynIter<'a> = Box<dyn Iterator<Item = char> + 'a>;
+
+/// An object safe version of [`Sanitizer`], which makes it possible to store a sanitizer,
+/// that is chosen at runtime, in a `Box<dyn DynSanitizer>` or a `Vec`.
+///
+/// It is implemented for every [`Sanitizer`], which implements [`Clone`].
+///
+/// ```
+/// use path_sanitizer::platforms::Windows;
+/// use path_sanitizer::{DynSanitizer, Platform, SanitizerExt};
+///
+/// let sanitizer: Box<dyn DynSanitizer> = Box::new(Windows::<'_', '_'>::default().filename_sanitizer());
+/// let sanitizer = sanitizer.then(Box::new(().replace_whitespace::<'-'>()));
+///
+/// assert_eq!(sanitizer.sanitize_str("a b?.txt"), "a-b_.txt");
+/// ```
+pub trait DynSanitizer {
+    #[must_use]
+    fn sanitize_dyn<'a>(&self, iter: DynIter<'a>) -> DynIter<'a>
+    where
+        Self: 'a;
+
+    /// A convenience function for sanitizing a string.
+    #[must_use]
+    fn sanitize_str(&self, string: &str) -> String {
+        self.sanitize_dyn(Box::new(string.chars())).collect()
+    }
+}
+
+impl<S: Sanitizer + Clone> DynSanitizer for S {
+    fn sanitize_dyn<'a>(&self, iter: DynIter<'a>) -> DynIter<'a>
+    where
+        Self: 'a,
+    {
+        Box::new(self.sanitize_ref(iter))
+    }
+}
+
+/// The sanitizers are applied in order, an empty `Vec` does not change anything.
+impl DynSanitizer for Vec<Box<dyn DynSanitizer + '_>> {
+    fn sanitize_dyn<'a>(&self, iter: DynIter<'a>) -> DynIter<'a>
+    where
+        Self: 'a,
+    {
+        self.iter()
+            .fold(iter, |iter, sanitizer| sanitizer.sanitize_dyn(iter))
+    }
+}
+
+impl<'s> dyn DynSanitizer + 's {
+    /// Chains two boxed sanitizers, first `self` is applied then `next`.
+    #[must_use]
+    pub fn then(self: Box<Self>, next: Box<dyn DynSanitizer + 's>) -> Box<dyn DynSanitizer + 's> {
+        Box::new(DynThen { a: self, b: next })
+    }
+}
+
+struct DynThen<'s> {
+    a: Box<dyn DynSanitizer + 's>,
+    b: Box<dyn DynSanitizer + 's>,
+}
+
+impl DynSanitizer for DynThen<'_> {
+    fn sanitize_dyn<'a>(&self, iter: DynIter<'a>) -> DynIter<'a>
+    where
+        Self: 'a,
+    {
+        self.b.sanitize_dyn(self.a.sanitize_dyn(iter))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::platforms::{Linux, Windows};
+    use crate::sanitizers::{Control, Whitespace};
+    use crate::{Platform, SanitizerExt};
+
+    use alloc::vec;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_blanket() {
+        let sanitizer = Whitespace::<'_'>::default();
+
+        assert_eq!(sanitizer.sanitize_str("a b\tc"), "a_b_c");
+        // the sanitizer can be used more than once
+        assert_eq!(sanitizer.sanitize_str(" "), "_");
+    }
+
+    #[test]
+    fn test_runtime_choice() {
+        let sanitizers: Vec<Box<dyn DynSanitizer>> = vec![
+            Box::new(Linux::<'_'>::default().filename_sanitizer()),
+            Box::new(Windows::<'_', '_'>::default().filename_sanitizer()),
+        ];
+
+        let results = sanitizers
+            .iter()
+            .map(|sanitizer| sanitizer.sanitize_str("a/b:c"))
+            .collect::<Vec<_>>();
+
+        assert_eq!(results, ["a_b:c", "a_b_c"]);
+    }
+
+    #[test]
+    fn test_chaining() {
+        let sanitizer: Box<dyn DynSanitizer> = Box::new(Control::<'_'>::default());
+        let sanitizer = sanitizer
+            .then(Box::new(().replace_whitespace::<'-'>()))
+            .then(Box::new(|c: char| (c != 'x').then_some(c)));
+
+        assert_eq!(sanitizer.sanitize_str("a\0b cx"), "a_b-c");
+    }
+
+    #[test]
+    fn test_vec() {
+        let fallback = String::from("?");
+        let sanitizers: Vec<Box<dyn DynSanitizer + '_>> = vec![
+            Box::new(().transliterate(&fallback)),
+            Box::new(Whitespace::<'_'>::default()),
+        ];
+
+        assert_eq!(sanitizers.sanitize_str("ä ö"), "ae_oe");
+        assert_eq!(Vec::new().sanitize_str("ä ö"), "ä ö");
+    }
+}
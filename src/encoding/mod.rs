@@ -0,0 +1,114 @@
+//! Sanitizing names, which are not valid Unicode, like the bytes of a Linux `OsStr`
+//! or the UTF-16 of a Windows `OsStr`, which may contain unpaired surrogates.
+mod utf16;
+mod utf8;
+
+pub use utf16::{sanitize_utf16, Utf16Decoder, Utf16Encoder};
+pub use utf8::{sanitize_utf8, sanitize_wtf8, Utf8Decoder, Utf8Encoder};
+
+use crate::sanitizers::PRIVATE_USE_OFFSET;
+
+/// Unpaired surrogates are mapped to `U+100000 + s`, which is in the supplementary private use area.
+pub const SURROGATE_OFFSET: u32 = 0x10_0000;
+
+/// How invalid UTF-8 and unpaired surrogates should be handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DecodeMode {
+    /// Replaces every invalid sequence and unpaired surrogate with `U+FFFD`,
+    /// like `String::from_utf8_lossy` or `String::from_utf16_lossy`.
+    Lossy,
+    /// Keeps the invalid bytes and unpaired surrogates, by mapping them into the private use area,
+    /// those characters are encoded as the original byte or surrogate again.
+    ///
+    /// Every invalid byte `b` is mapped to `U+F000 + b` like Cygwin does and every unpaired surrogate `s`
+    /// to `U+100000 + s`. Valid characters in the ranges `U+F080..=U+F0FF` and `U+10D800..=U+10DFFF`
+    /// would be encoded as a single byte or surrogate too, so those are replaced with `U+FFFD` instead.
+    PassThrough,
+}
+
+impl DecodeMode {
+    #[must_use]
+    fn decode_byte(self, byte: u8) -> char {
+        match self {
+            Self::Lossy => char::REPLACEMENT_CHARACTER,
+            Self::PassThrough => char::from_u32(PRIVATE_USE_OFFSET + u32::from(byte))
+                .unwrap_or(char::REPLACEMENT_CHARACTER),
+        }
+    }
+
+    #[must_use]
+    fn encode_byte(self, c: char) -> Option<u8> {
+        match self {
+            Self::Lossy => None,
+            Self::PassThrough => u32::from(c)
+                .checked_sub(PRIVATE_USE_OFFSET)
+                .and_then(|b| u8::try_from(b).ok())
+                .filter(|b| !b.is_ascii()),
+        }
+    }
+
+    /// Replaces the valid character with `U+FFFD`, if it would be encoded as an invalid byte
+    /// or as an unpaired surrogate, when `surrogates` are encoded.
+    #[must_use]
+    fn decode_valid(self, c: char, surrogates: bool) -> char {
+        if self.encode_byte(c).is_some() || (surrogates && self.encode_surrogate(c).is_some()) {
+            char::REPLACEMENT_CHARACTER
+        } else {
+            c
+        }
+    }
+
+    #[must_use]
+    fn decode_surrogate(self, surrogate: u16) -> char {
+        match self {
+            Self::Lossy => char::REPLACEMENT_CHARACTER,
+            Self::PassThrough => char::from_u32(SURROGATE_OFFSET + u32::from(surrogate))
+                .unwrap_or(char::REPLACEMENT_CHARACTER),
+        }
+    }
+
+    #[must_use]
+    fn encode_surrogate(self, c: char) -> Option<u16> {
+        match self {
+            Self::Lossy => None,
+            Self::PassThrough => u32::from(c)
+                .checked_sub(SURROGATE_OFFSET)
+                .and_then(|s| u16::try_from(s).ok())
+                .filter(|s| is_surrogate(*s)),
+        }
+    }
+}
+
+/// Returns the number of UTF-16 code units of the character, the mapped unpaired surrogates
+/// are encoded as a single code unit.
+#[must_use]
+pub(crate) const fn len_utf16(c: char) -> usize {
+    match (c as u32).checked_sub(SURROGATE_OFFSET) {
+        Some(0xD800..=0xDFFF) => 1,
+        _ => c.len_utf16(),
+    }
+}
+
+#[must_use]
+const fn is_surrogate(unit: u16) -> bool {
+    matches!(unit, 0xD800..=0xDFFF)
+}
+
+#[must_use]
+const fn is_low_surrogate(unit: u16) -> bool {
+    matches!(unit, 0xDC00..=0xDFFF)
+}
+
+#[cfg(test)]
+fn assert_units_eq<T: PartialEq + core::fmt::Debug + Copy, I: Iterator<Item = T>>(
+    iter: I,
+    expected: &[T],
+) {
+    let mut expected = expected.iter().copied();
+
+    for unit in iter {
+        pretty_assertions::assert_eq!(Some(unit), expected.next());
+    }
+
+    pretty_assertions::assert_eq!(None, expected.next());
+}
@@ -0,0 +1,166 @@
+use core::iter::Peekable;
+
+use super::{is_low_surrogate, is_surrogate, DecodeMode};
+use crate::sanitizer::Sanitizer;
+
+/// Decodes UTF-16, like the names returned by the Windows API, into characters,
+/// unpaired surrogates are handled according to the [`DecodeMode`].
+#[derive(Debug, Clone)]
+pub struct Utf16Decoder<I: Iterator<Item = u16>> {
+    iter: Peekable<I>,
+    mode: DecodeMode,
+}
+
+impl<I: Iterator<Item = u16>> Utf16Decoder<I> {
+    #[must_use]
+    pub fn new<T: IntoIterator<IntoIter = I>>(units: T, mode: DecodeMode) -> Self {
+        Self {
+            iter: units.into_iter().peekable(),
+            mode,
+        }
+    }
+}
+
+impl<I: Iterator<Item = u16>> Iterator for Utf16Decoder<I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let unit = self.iter.next()?;
+
+        if !is_surrogate(unit) {
+            return char::from_u32(u32::from(unit));
+        }
+
+        if unit <= 0xDBFF {
+            if let Some(low) = self.iter.next_if(|u| is_low_surrogate(*u)) {
+                let c = 0x1_0000 + ((u32::from(unit) - 0xD800) << 10) + (u32::from(low) - 0xDC00);
+                return char::from_u32(c).map(|c| self.mode.decode_valid(c, true));
+            }
+        }
+
+        Some(self.mode.decode_surrogate(unit))
+    }
+}
+
+/// Encodes characters as UTF-16, in [`DecodeMode::PassThrough`] the characters,
+/// which represent unpaired surrogates, are encoded as the original surrogate.
+///
+/// An unpaired high surrogate followed by an unpaired low surrogate would form a pair,
+/// like after a sanitizer removed the characters between them, so `U+FFFD` is kept between both.
+#[derive(Debug, Clone)]
+pub struct Utf16Encoder<I: Iterator<Item = char>> {
+    iter: I,
+    mode: DecodeMode,
+    /// the low surrogate of the last character
+    low: Option<u16>,
+    /// true if the last unit was an unpaired high surrogate
+    high: bool,
+}
+
+impl<I: Iterator<Item = char>> Utf16Encoder<I> {
+    #[must_use]
+    pub const fn new(iter: I, mode: DecodeMode) -> Self {
+        Self {
+            iter,
+            mode,
+            low: None,
+            high: false,
+        }
+    }
+}
+
+impl<I: Iterator<Item = char>> Iterator for Utf16Encoder<I> {
+    type Item = u16;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(low) = self.low.take() {
+            return Some(low);
+        }
+
+        let c = self.iter.next()?;
+        if let Some(surrogate) = self.mode.encode_surrogate(c) {
+            if self.high && is_low_surrogate(surrogate) {
+                self.high = false;
+                self.low = Some(surrogate);
+                return Some(char::REPLACEMENT_CHARACTER as u16);
+            }
+
+            self.high = !is_low_surrogate(surrogate);
+            return Some(surrogate);
+        }
+
+        self.high = false;
+        let mut units = [0; 2];
+        let units = c.encode_utf16(&mut units);
+        self.low = units.get(1).copied();
+
+        Some(units[0])
+    }
+}
+
+/// Sanitizes UTF-16, the unpaired surrogates are handled according to the [`DecodeMode`].
+#[must_use]
+pub fn sanitize_utf16<S: Sanitizer, I: IntoIterator<Item = u16>>(
+    sanitizer: S,
+    units: I,
+    mode: DecodeMode,
+) -> Utf16Encoder<S::Iter<Utf16Decoder<I::IntoIter>>> {
+    Utf16Encoder::new(sanitizer.sanitize(Utf16Decoder::new(units, mode)), mode)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoding::assert_units_eq;
+    use crate::sanitizers::{Invisible, REMOVE};
+
+    // `a😄` followed by an unpaired high surrogate, `b` and an unpaired low surrogate
+    const UNITS: [u16; 6] = [0x61, 0xD83D, 0xDE04, 0xD83D, 0x62, 0xDE04];
+
+    #[test]
+    fn test_decode() {
+        let iter = Utf16Decoder::new(UNITS, DecodeMode::Lossy);
+        assert_iter_eq_str!(iter, "a\u{1F604}\u{FFFD}b\u{FFFD}");
+
+        let iter = Utf16Decoder::new(UNITS, DecodeMode::PassThrough);
+        assert_iter_eq_str!(iter, "a\u{1F604}\u{10D83D}b\u{10DE04}");
+    }
+
+    #[test]
+    fn test_pass_through_private_use() {
+        // the valid `U+10D83D` would be encoded as the unpaired surrogate `U+D83D`
+        let units = [0x61, 0xDBF6, 0xDC3D, 0xF0E9];
+
+        let iter = sanitize_utf16(Some, units, DecodeMode::PassThrough);
+        assert_units_eq(iter, &[0x61, 0xFFFD, 0xF0E9]);
+
+        let iter = sanitize_utf16(Some, units, DecodeMode::Lossy);
+        assert_units_eq(iter, &units);
+    }
+
+    #[test]
+    fn test_adjacent_surrogates() {
+        let sanitizer = Invisible::<REMOVE>::default();
+        // an unpaired high surrogate, a zero width space and an unpaired low surrogate
+        let units = [0xD83D, 0x200B, 0xDE00];
+
+        let iter = sanitize_utf16(sanitizer, units, DecodeMode::PassThrough);
+        assert_units_eq(iter, &[0xD83D, 0xFFFD, 0xDE00]);
+
+        // only a high surrogate followed by a low surrogate forms a pair
+        let units = [0xDE00, 0x200B, 0xD83D, 0x200B, 0xD83D];
+        let iter = sanitize_utf16(sanitizer, units, DecodeMode::PassThrough);
+        assert_units_eq(iter, &[0xDE00, 0xD83D, 0xD83D]);
+    }
+
+    #[test]
+    fn test_sanitize() {
+        let sanitizer = |c: char| Some(if c == 'b' { '_' } else { c });
+
+        let iter = sanitize_utf16(sanitizer, UNITS, DecodeMode::PassThrough);
+        assert_units_eq(iter, &[0x61, 0xD83D, 0xDE04, 0xD83D, 0x5F, 0xDE04]);
+
+        let iter = sanitize_utf16(sanitizer, UNITS, DecodeMode::Lossy);
+        assert_units_eq(iter, &[0x61, 0xD83D, 0xDE04, 0xFFFD, 0x5F, 0xFFFD]);
+    }
+}
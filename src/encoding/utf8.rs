@@ -0,0 +1,308 @@
+use core::iter::Peekable;
+use core::ops::RangeInclusive;
+
+use super::{is_low_surrogate, DecodeMode};
+use crate::sanitizer::Sanitizer;
+use crate::utils::Buffer;
+
+/// Decodes UTF-8 bytes into characters, invalid sequences are handled according to the [`DecodeMode`].
+///
+/// The decoder can also decode WTF-8, which is used by Rust for Windows `OsStr`
+/// and may contain unpaired surrogates, see <https://simonsapin.github.io/wtf-8/>.
+#[derive(Debug, Clone)]
+pub struct Utf8Decoder<I: Iterator<Item = u8>> {
+    iter: Peekable<I>,
+    mode: DecodeMode,
+    is_wtf8: bool,
+    /// the remaining characters of an invalid sequence
+    buffer: Buffer<4>,
+}
+
+impl<I: Iterator<Item = u8>> Utf8Decoder<I> {
+    #[must_use]
+    pub fn new<T: IntoIterator<IntoIter = I>>(bytes: T, mode: DecodeMode) -> Self {
+        Self {
+            iter: bytes.into_iter().peekable(),
+            mode,
+            is_wtf8: false,
+            buffer: Buffer::new(),
+        }
+    }
+
+    /// Creates a decoder, which accepts encoded surrogates.
+    #[must_use]
+    pub fn wtf8<T: IntoIterator<IntoIter = I>>(bytes: T, mode: DecodeMode) -> Self {
+        Self {
+            is_wtf8: true,
+            ..Self::new(bytes, mode)
+        }
+    }
+
+    /// Handles the maximal prefix of a valid sequence, which is not valid on its own.
+    fn invalid(&mut self, bytes: &[u8]) -> Option<char> {
+        match self.mode {
+            DecodeMode::Lossy => Some(char::REPLACEMENT_CHARACTER),
+            DecodeMode::PassThrough => {
+                for byte in bytes {
+                    self.buffer.push(self.mode.decode_byte(*byte));
+                }
+
+                self.buffer.next()
+            }
+        }
+    }
+
+    /// Returns the valid range of the continuation byte at `index` (starting at 1),
+    /// see table 3-7 in the Unicode standard.
+    #[must_use]
+    const fn continuation(&self, first: u8, index: usize) -> RangeInclusive<u8> {
+        match (first, index) {
+            (0xE0, 1) => 0xA0..=0xBF,
+            // those would be surrogates
+            (0xED, 1) if !self.is_wtf8 => 0x80..=0x9F,
+            (0xF0, 1) => 0x90..=0xBF,
+            (0xF4, 1) => 0x80..=0x8F,
+            _ => 0x80..=0xBF,
+        }
+    }
+}
+
+impl<I: Iterator<Item = u8>> Iterator for Utf8Decoder<I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(c) = self.buffer.next() {
+            return Some(c);
+        }
+
+        let first = self.iter.next()?;
+        let width = match first {
+            0x00..=0x7F => return Some(char::from(first)),
+            0xC2..=0xDF => 2,
+            0xE0..=0xEF => 3,
+            0xF0..=0xF4 => 4,
+            _ => return self.invalid(&[first]),
+        };
+
+        let mut bytes = [first, 0, 0, 0];
+        for i in 1..width {
+            let range = self.continuation(first, i);
+
+            match self.iter.next_if(|b| range.contains(b)) {
+                Some(byte) => bytes[i] = byte,
+                None => return self.invalid(&bytes[..i]),
+            }
+        }
+
+        // only WTF-8 can contain surrogates `U+D800..=U+DFFF`, which are encoded as `ED A0..=BF 80..=BF`
+        if first == 0xED && bytes[1] >= 0xA0 {
+            let surrogate = 0xD000 | (u16::from(bytes[1] & 0x3F) << 6) | u16::from(bytes[2] & 0x3F);
+            return Some(self.mode.decode_surrogate(surrogate));
+        }
+
+        core::str::from_utf8(&bytes[..width])
+            .ok()
+            .and_then(|s| s.chars().next())
+            .map(|c| self.mode.decode_valid(c, self.is_wtf8))
+    }
+}
+
+/// Encodes characters as UTF-8, in [`DecodeMode::PassThrough`] the characters,
+/// which represent invalid bytes, are encoded as the original byte.
+///
+/// When encoding WTF-8, the characters representing unpaired surrogates are encoded as surrogates,
+/// `U+FFFD` is kept between an unpaired high surrogate and an unpaired low surrogate,
+/// because WTF-8 does not allow encoding a surrogate pair as two surrogates.
+#[derive(Debug, Clone)]
+pub struct Utf8Encoder<I: Iterator<Item = char>> {
+    iter: I,
+    mode: DecodeMode,
+    is_wtf8: bool,
+    bytes: [u8; 4],
+    start: usize,
+    end: usize,
+    /// the unpaired low surrogate, which follows the `U+FFFD` after an unpaired high surrogate
+    low: Option<char>,
+    /// true if the last character was encoded as an unpaired high surrogate
+    high: bool,
+}
+
+impl<I: Iterator<Item = char>> Utf8Encoder<I> {
+    #[must_use]
+    pub const fn new(iter: I, mode: DecodeMode) -> Self {
+        Self {
+            iter,
+            mode,
+            is_wtf8: false,
+            bytes: [0; 4],
+            start: 0,
+            end: 0,
+            low: None,
+            high: false,
+        }
+    }
+
+    /// Creates an encoder, which encodes unpaired surrogates like WTF-8.
+    #[must_use]
+    pub const fn wtf8(iter: I, mode: DecodeMode) -> Self {
+        Self {
+            iter,
+            mode,
+            is_wtf8: true,
+            bytes: [0; 4],
+            start: 0,
+            end: 0,
+            low: None,
+            high: false,
+        }
+    }
+}
+
+impl<I: Iterator<Item = char>> Iterator for Utf8Encoder<I> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            let c = match self.low.take() {
+                Some(c) => c,
+                None => self.iter.next()?,
+            };
+
+            if let Some(byte) = self.mode.encode_byte(c) {
+                self.high = false;
+                return Some(byte);
+            }
+
+            self.start = 0;
+            self.end = match self.mode.encode_surrogate(c) {
+                Some(surrogate) if self.is_wtf8 && self.high && is_low_surrogate(surrogate) => {
+                    self.high = false;
+                    self.low = Some(c);
+                    char::REPLACEMENT_CHARACTER
+                        .encode_utf8(&mut self.bytes)
+                        .len()
+                }
+                Some(surrogate) if self.is_wtf8 => {
+                    self.high = !is_low_surrogate(surrogate);
+                    // surrogates have the same 3 byte encoding as all other characters in the BMP
+                    self.bytes[..3].copy_from_slice(&[
+                        0xED,
+                        0x80 | ((surrogate >> 6) & 0x3F) as u8,
+                        0x80 | (surrogate & 0x3F) as u8,
+                    ]);
+                    3
+                }
+                _ => {
+                    self.high = false;
+                    c.encode_utf8(&mut self.bytes).len()
+                }
+            };
+        }
+
+        self.start += 1;
+        Some(self.bytes[self.start - 1])
+    }
+}
+
+/// Sanitizes bytes, which should be UTF-8, the invalid sequences are handled according to the [`DecodeMode`].
+#[must_use]
+pub fn sanitize_utf8<S: Sanitizer, I: IntoIterator<Item = u8>>(
+    sanitizer: S,
+    bytes: I,
+    mode: DecodeMode,
+) -> Utf8Encoder<S::Iter<Utf8Decoder<I::IntoIter>>> {
+    Utf8Encoder::new(sanitizer.sanitize(Utf8Decoder::new(bytes, mode)), mode)
+}
+
+/// Sanitizes WTF-8 bytes, the unpaired surrogates are handled according to the [`DecodeMode`].
+#[must_use]
+pub fn sanitize_wtf8<S: Sanitizer, I: IntoIterator<Item = u8>>(
+    sanitizer: S,
+    bytes: I,
+    mode: DecodeMode,
+) -> Utf8Encoder<S::Iter<Utf8Decoder<I::IntoIter>>> {
+    Utf8Encoder::wtf8(sanitizer.sanitize(Utf8Decoder::wtf8(bytes, mode)), mode)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoding::assert_units_eq;
+
+    #[test]
+    fn test_decode_lossy() {
+        let bytes = b"a\xF1\x80\x80b\xE0\x80c\xED\xA0\x80\xFF\xC3\xA4";
+        let iter = Utf8Decoder::new(bytes.iter().copied(), DecodeMode::Lossy);
+
+        // same as `String::from_utf8_lossy`
+        assert_iter_eq_str!(
+            iter,
+            "a\u{FFFD}b\u{FFFD}\u{FFFD}c\u{FFFD}\u{FFFD}\u{FFFD}\u{FFFD}\u{00E4}"
+        );
+    }
+
+    #[test]
+    fn test_decode_pass_through() {
+        let bytes = b"a\xF1\x80\x80b\xFF";
+        let iter = Utf8Decoder::new(bytes.iter().copied(), DecodeMode::PassThrough);
+
+        assert_iter_eq_str!(iter, "a\u{F0F1}\u{F080}\u{F080}b\u{F0FF}");
+    }
+
+    #[test]
+    fn test_sanitize_pass_through() {
+        let bytes = b"Caf\xE9/\xC3\xA4\x00";
+        let sanitizer = |c: char| Some(if matches!(c, '/' | '\0') { '_' } else { c });
+
+        let iter = sanitize_utf8(sanitizer, bytes.iter().copied(), DecodeMode::PassThrough);
+        assert_units_eq(iter, b"Caf\xE9_\xC3\xA4_");
+
+        let iter = sanitize_utf8(sanitizer, bytes.iter().copied(), DecodeMode::Lossy);
+        assert_units_eq(iter, "Caf\u{FFFD}_\u{00E4}_".as_bytes());
+    }
+
+    #[test]
+    fn test_pass_through_private_use() {
+        // the valid `U+F0E9` would be encoded as the invalid byte `E9`
+        let bytes = "a\u{F0E9}\u{F07F}\u{10D83D}".as_bytes();
+
+        let iter = sanitize_utf8(Some, bytes.iter().copied(), DecodeMode::PassThrough);
+        assert_units_eq(iter, "a\u{FFFD}\u{F07F}\u{10D83D}".as_bytes());
+
+        // WTF-8 would encode `U+10D83D` as the unpaired surrogate `U+D83D`
+        let iter = sanitize_wtf8(Some, bytes.iter().copied(), DecodeMode::PassThrough);
+        assert_units_eq(iter, "a\u{FFFD}\u{F07F}\u{FFFD}".as_bytes());
+
+        let iter = sanitize_utf8(Some, bytes.iter().copied(), DecodeMode::Lossy);
+        assert_units_eq(iter, bytes);
+    }
+
+    #[test]
+    fn test_wtf8() {
+        // an unpaired high surrogate `U+D83D` followed by `a`
+        let bytes = b"\xED\xA0\xBDa\xF0\x9F\x98\x84";
+
+        let iter = Utf8Decoder::wtf8(bytes.iter().copied(), DecodeMode::PassThrough);
+        assert_iter_eq_str!(iter, "\u{10D83D}a\u{1F604}");
+
+        let iter = sanitize_wtf8(Some, bytes.iter().copied(), DecodeMode::PassThrough);
+        assert_units_eq(iter, bytes);
+
+        let iter = sanitize_wtf8(Some, bytes.iter().copied(), DecodeMode::Lossy);
+        assert_units_eq(iter, "\u{FFFD}a\u{1F604}".as_bytes());
+
+        // UTF-8 does not allow surrogates
+        let iter = Utf8Decoder::new(bytes.iter().copied(), DecodeMode::Lossy);
+        assert_iter_eq_str!(iter, "\u{FFFD}\u{FFFD}\u{FFFD}a\u{1F604}");
+    }
+
+    #[test]
+    fn test_wtf8_adjacent_surrogates() {
+        // an unpaired high surrogate, a zero width space and an unpaired low surrogate
+        let bytes = b"\xED\xA0\xBD\xE2\x80\x8B\xED\xB8\x80";
+        let sanitizer = |c: char| (c != '\u{200B}').then_some(c);
+
+        let iter = sanitize_wtf8(sanitizer, bytes.iter().copied(), DecodeMode::PassThrough);
+        assert_units_eq(iter, b"\xED\xA0\xBD\xEF\xBF\xBD\xED\xB8\x80");
+    }
+}
@@ -1,7 +1,4 @@
 #![cfg_attr(not(feature = "std"), no_std)]
-#![feature(generic_associated_types)]
-#![feature(type_alias_impl_trait)]
-#![feature(const_generics_defaults)]
 #![warn(clippy::nursery, clippy::pedantic)]
 #![allow(
     clippy::module_name_repetitions,
@@ -9,26 +6,56 @@
     clippy::redundant_pub_crate
 )]
 
+#[cfg(test)]
+macro_rules! assert_iter_eq_str {
+    ( $iter:expr, $string:expr ) => {{
+        let mut chars = $string.chars();
+
+        for c in $iter {
+            assert_eq!(Some(c), chars.next());
+        }
+
+        assert_eq!(None, chars.next());
+    }};
+}
+
+#[cfg(feature = "serde")]
+pub mod config;
+#[cfg(feature = "confusables")]
+pub mod confusables;
+#[cfg(feature = "alloc")]
+mod dyn_sanitizer;
+pub mod encoding;
 mod platform;
 pub mod platforms;
+pub mod report;
 mod sanitizer;
 mod sanitizer_ext;
 pub mod sanitizers;
 mod utils;
 
+#[cfg(feature = "alloc")]
+pub use dyn_sanitizer::{DynIter, DynSanitizer};
 pub use platform::Platform;
-pub use sanitizer::Sanitizer;
+pub use sanitizer::{BoolClosureAdapter, Sanitizer};
 pub use sanitizer_ext::SanitizerExt;
+pub use utils::{AsciiMap, AsciiSet, Map, Sorted};
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
 #[cfg(feature = "alloc")]
 use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(all(feature = "std", any(unix, windows)))]
+use std::ffi::{OsStr, OsString};
+
+#[cfg(feature = "alloc")]
+use crate::encoding::DecodeMode;
 
 // TODO: https://github.com/hkalexling/Mango/issues/212
 // TODO: https://github.com/szTheory/zaru_crystal
-// TODO: add macos support
 // TODO: support for filesystems?
 
 #[macro_export]
@@ -44,10 +71,10 @@ macro_rules! count {
 /// this macro is used, so one does not have to specify the number of elements in the array manually
 #[macro_export]
 macro_rules! constant_arrays {
-    ( $( $( #[doc = $doc:expr] )* const $name:ident : [$type:ty; _] = [$( $x:expr ),+ $(,)? ]);+ $(;)? ) => {
+    ( $( $( #[doc = $doc:expr] )* $vis:vis const $name:ident : [$type:ty; _] = [$( $x:expr ),+ $(,)? ]);+ $(;)? ) => {
         $(
             $( #[doc = $doc] )*
-            const $name : [$type; $crate::count!( $($x),+ )] = [ $($x),+];
+            $vis const $name : [$type; $crate::count!( $($x),+ )] = [ $($x),+];
         )+
     };
 }
@@ -56,12 +83,13 @@ macro_rules! constant_arrays {
 #[cfg(feature = "alloc")]
 #[must_use]
 pub fn sanitize(string: &str, sanitizer: impl Sanitizer) -> String {
-    sanitizer.sanitize(string.chars()).into_iter().collect()
+    sanitizer.sanitize(string.chars()).collect()
 }
 
 /// A convenience function for sanitizing a filename.
 #[cfg(feature = "alloc")]
 #[must_use]
+#[allow(clippy::needless_pass_by_value)]
 pub fn sanitize_filename(string: &str, platform: impl Platform) -> String {
     sanitize(string, platform.filename_sanitizer())
 }
@@ -69,6 +97,42 @@ pub fn sanitize_filename(string: &str, platform: impl Platform) -> String {
 /// A convenience function for sanitizing a folder.
 #[cfg(feature = "alloc")]
 #[must_use]
+#[allow(clippy::needless_pass_by_value)]
 pub fn sanitize_folder(string: &str, platform: impl Platform) -> String {
     sanitize(string, platform.folder_sanitizer())
 }
+
+/// A convenience function for sanitizing bytes, which should be UTF-8.
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn sanitize_bytes(bytes: &[u8], sanitizer: impl Sanitizer, mode: DecodeMode) -> Vec<u8> {
+    encoding::sanitize_utf8(sanitizer, bytes.iter().copied(), mode).collect()
+}
+
+/// A convenience function for sanitizing UTF-16, which may contain unpaired surrogates.
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn sanitize_wide(units: &[u16], sanitizer: impl Sanitizer, mode: DecodeMode) -> Vec<u16> {
+    encoding::sanitize_utf16(sanitizer, units.iter().copied(), mode).collect()
+}
+
+/// A convenience function for sanitizing an `OsStr`, like the names returned by `read_dir`,
+/// without converting it to a `String` first.
+#[cfg(all(feature = "std", unix))]
+#[must_use]
+pub fn sanitize_os_str(name: &OsStr, sanitizer: impl Sanitizer, mode: DecodeMode) -> OsString {
+    use std::os::unix::ffi::{OsStrExt, OsStringExt};
+
+    OsString::from_vec(sanitize_bytes(name.as_bytes(), sanitizer, mode))
+}
+
+/// A convenience function for sanitizing an `OsStr`, like the names returned by `read_dir`,
+/// without converting it to a `String` first.
+#[cfg(all(feature = "std", windows))]
+#[must_use]
+pub fn sanitize_os_str(name: &OsStr, sanitizer: impl Sanitizer, mode: DecodeMode) -> OsString {
+    use std::os::windows::ffi::{OsStrExt, OsStringExt};
+
+    let units = encoding::sanitize_utf16(sanitizer, name.encode_wide(), mode).collect::<Vec<_>>();
+    OsString::from_wide(&units)
+}
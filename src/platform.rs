@@ -1,8 +1,12 @@
 use crate::Sanitizer;
 
 pub trait Platform: Default {
-    type FilenameSanitizer<'a>: Sanitizer + 'a;
-    type FolderSanitizer<'a>: Sanitizer + 'a;
+    type FilenameSanitizer<'a>: Sanitizer + 'a
+    where
+        Self: 'a;
+    type FolderSanitizer<'a>: Sanitizer + 'a
+    where
+        Self: 'a;
 
     #[must_use]
     fn filename_sanitizer(&self) -> Self::FilenameSanitizer<'_>;
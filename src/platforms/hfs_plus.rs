@@ -0,0 +1,102 @@
+use core::str::Chars;
+
+use super::{nfd, Normalization};
+use crate::sanitizer_ext::SanitizerExt;
+use crate::sanitizers::{replacement, Invisible, Padder, Replacer, Then, Truncate};
+use crate::{constant_arrays, AsciiSet, Platform};
+
+/// The sanitizer for filenames and folders on HFS+.
+type HfsPlusSanitizer<const RP: char> = Then<
+    Then<
+        Then<Then<Invisible<RP>, Replacer<(AsciiSet, Option<char>), Option<char>>>, Normalization>,
+        Truncate,
+    >,
+    Padder<Chars<'static>, '_', 3>,
+>;
+
+/// The maximum length of a file or folder name in UTF-16 code units
+const MAX_LENGTH: usize = 255;
+
+/// The HFS+ filesystem used by macOS, which stores names in (a variant of) NFD.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HfsPlus<const RP: char> {}
+
+impl<const RP: char> Default for HfsPlus<RP> {
+    fn default() -> Self {
+        Self {}
+    }
+}
+
+impl<const RP: char> HfsPlus<RP> {
+    constant_arrays! {
+        /// The `/` is the path separator and the `:` is the separator of the Carbon APIs,
+        /// which is displayed as `/` in the Finder.
+        const FORBIDDEN_CHARACTERS: [char; _] = ['/', ':', '\x00'];
+    }
+
+    const FORBIDDEN_SET: AsciiSet = AsciiSet::from_chars(&Self::FORBIDDEN_CHARACTERS);
+
+    /// The maximum length of a file or folder name in UTF-16 code units
+    pub const MAX_LENGTH: usize = MAX_LENGTH;
+}
+
+impl<const RP: char> Platform for HfsPlus<RP> {
+    type FilenameSanitizer<'a> = HfsPlusSanitizer<RP>;
+    type FolderSanitizer<'a> = HfsPlusSanitizer<RP>;
+
+    fn filename_sanitizer(&self) -> Self::FilenameSanitizer<'_> {
+        Invisible::<RP>::default()
+            .then(Replacer::from((Self::FORBIDDEN_SET, replacement::<RP>())))
+            // normalize after removing characters, which could change the normalization
+            .then(nfd())
+            // the names are stored in UTF-16
+            .truncate_utf16(MAX_LENGTH)
+            // the empty name and the relative paths `.` and `..` can not be used as a name
+            .padding::<'_', 3>(["", ".", ".."])
+    }
+
+    fn folder_sanitizer(&self) -> Self::FolderSanitizer<'_> {
+        Invisible::<RP>::default()
+            .then(Replacer::from((Self::FORBIDDEN_SET, replacement::<RP>())))
+            .then(nfd())
+            .truncate_utf16(MAX_LENGTH)
+            .padding::<'_', 3>(["", ".", ".."])
+    }
+}
+
+#[cfg(all(test, feature = "normalization"))]
+mod tests {
+    use super::*;
+    use crate::Sanitizer;
+
+    #[test]
+    fn test_filename_nfd() {
+        let platform: HfsPlus<'_'> = HfsPlus::default();
+        let iter = platform
+            .filename_sanitizer()
+            .sanitize("caf\u{00E9}: 1/2".chars());
+
+        assert_iter_eq_str!(iter, "cafe\u{0301}_ 1_2");
+    }
+
+    #[test]
+    fn test_relative() {
+        let platform: HfsPlus<'_'> = HfsPlus::default();
+
+        assert_iter_eq_str!(platform.filename_sanitizer().sanitize("".chars()), "_");
+        assert_iter_eq_str!(platform.filename_sanitizer().sanitize(".".chars()), "._");
+        assert_iter_eq_str!(platform.folder_sanitizer().sanitize("..".chars()), ".._");
+        assert_iter_eq_str!(platform.folder_sanitizer().sanitize("...".chars()), "...");
+    }
+
+    #[test]
+    fn test_max_length() {
+        let platform: HfsPlus<'_'> = HfsPlus::default();
+        let iter = platform
+            .filename_sanitizer()
+            .sanitize(core::iter::repeat_n('\u{00E9}', 200));
+
+        // every `é` is decomposed into two UTF-16 code units
+        assert_eq!(iter.count(), 255);
+    }
+}
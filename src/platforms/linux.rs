@@ -1,7 +1,40 @@
+use core::str::Chars;
+
+use super::{nfc, BuildError, Normalization, ReplaceWith};
 use crate::constant_arrays;
-use crate::sanitizer::Sanitizer;
-use crate::sanitizers::Replacer;
-use crate::Platform;
+use crate::sanitizer_ext::SanitizerExt;
+use crate::sanitizers::{
+    is_invisible, replacement, replacement_for, Categories, Padder, Replacer,
+    RuntimeGeneralCategory, RuntimeInvisible, Then, Truncate, REMOVE,
+};
+use crate::{AsciiSet, Platform};
+
+/// The sanitizer for filenames and folders on Linux.
+type LinuxSanitizer = Then<
+    Then<
+        Then<
+            Then<Then<RuntimeInvisible, RuntimeGeneralCategory>, ReplaceWith<AsciiSet>>,
+            Normalization,
+        >,
+        Truncate,
+    >,
+    Padder<Chars<'static>, '_', 3>,
+>;
+
+constant_arrays! {
+    /// Linux only forbids those two characters:
+    const FORBIDDEN_CHARACTERS: [char; _] = ['/', '\x00'];
+}
+
+pub(super) const FORBIDDEN_SET: AsciiSet = AsciiSet::from_chars(&FORBIDDEN_CHARACTERS);
+
+/// The maximum length of a file or folder name in bytes
+const MAX_LENGTH: usize = 255;
+
+/// Returns true if the character is replaced by the platform.
+fn is_replaced(c: char) -> bool {
+    FORBIDDEN_SET.contains(c) || is_invisible(c) || Categories::DEFAULT.matches(c)
+}
 
 // TODO: make default const RP = '\u{FFFD}'
 // NOTE: currently rustfmt destroys any defaults
@@ -17,22 +50,166 @@ impl<const RP: char> Default for Linux<RP> {
 // TODO: Do something with SHOULD_BE_FORBIDDEN?
 impl<const RP: char> Linux<RP> {
     constant_arrays! {
-        /// Linux only forbids those two characters:
-        const FORBIDDEN_CHARACTERS: [char; _] = ['/', '\x00'];
         /// Characters that are allowed, but make it very difficult to work with in shells
-        const SHOULD_BE_FORBIDDEN: [char; _] = ['~', '\\', '"'];
+        pub const SHOULD_BE_FORBIDDEN: [char; _] = ['~', '\\', '"'];
     }
+
+    /// The maximum length of a file or folder name in bytes
+    pub const MAX_LENGTH: usize = MAX_LENGTH;
+
+    const RUNTIME: RuntimeLinux = RuntimeLinux {
+        replacement: replacement::<RP>(),
+    };
 }
 
 impl<const RP: char> Platform for Linux<RP> {
-    type FilenameSanitizer<'a> = impl Sanitizer + 'a;
-    type FolderSanitizer<'a> = Replacer<[(char, char); 2]>;
+    type FilenameSanitizer<'a> = LinuxSanitizer;
+    type FolderSanitizer<'a> = LinuxSanitizer;
+
+    fn filename_sanitizer(&self) -> Self::FilenameSanitizer<'_> {
+        Self::RUNTIME.filename_sanitizer()
+    }
+
+    fn folder_sanitizer(&self) -> Self::FolderSanitizer<'_> {
+        Self::RUNTIME.folder_sanitizer()
+    }
+}
+
+/// The same rules as [`Linux`], but the replacement character is chosen at runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RuntimeLinux {
+    replacement: Option<char>,
+}
+
+impl RuntimeLinux {
+    #[must_use]
+    pub const fn builder() -> LinuxBuilder {
+        LinuxBuilder::new()
+    }
+}
+
+impl Default for RuntimeLinux {
+    fn default() -> Self {
+        Linux::<'\u{FFFD}'>::RUNTIME
+    }
+}
+
+impl Platform for RuntimeLinux {
+    type FilenameSanitizer<'a> = LinuxSanitizer;
+    type FolderSanitizer<'a> = LinuxSanitizer;
 
     fn filename_sanitizer(&self) -> Self::FilenameSanitizer<'_> {
-        Replacer::from(Self::FORBIDDEN_CHARACTERS.map(|c| (c, RP)))
+        RuntimeInvisible::new(self.replacement.unwrap_or(REMOVE))
+            .then(RuntimeGeneralCategory::new(
+                Categories::DEFAULT,
+                self.replacement.unwrap_or(REMOVE),
+            ))
+            .then(Replacer::from((FORBIDDEN_SET, self.replacement)))
+            // normalize after removing characters, which could change the normalization
+            .then(nfc())
+            .truncate(MAX_LENGTH)
+            // the empty name and the relative paths `.` and `..` can not be used as a name
+            .padding::<'_', 3>(["", ".", ".."])
     }
 
     fn folder_sanitizer(&self) -> Self::FolderSanitizer<'_> {
-        Replacer::from(Self::FORBIDDEN_CHARACTERS.map(|c| (c, RP)))
+        self.filename_sanitizer()
+    }
+}
+
+/// Configures a [`RuntimeLinux`], by default the replacement character is `U+FFFD`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LinuxBuilder {
+    replacement: Option<char>,
+}
+
+impl LinuxBuilder {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            replacement: Some('\u{FFFD}'),
+        }
+    }
+
+    /// Sets the replacement character, [`REMOVE`](crate::sanitizers::REMOVE) removes the characters instead.
+    #[must_use]
+    pub const fn replacement(mut self, replacement: char) -> Self {
+        self.replacement = replacement_for(replacement);
+        self
+    }
+
+    /// Returns the platform, or an error if the replacement character is replaced by the platform.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BuildError::Replacement`] for characters like `/` or `\n`.
+    pub fn build(self) -> Result<RuntimeLinux, BuildError> {
+        match self.replacement {
+            Some(c) if is_replaced(c) => Err(BuildError::Replacement(c)),
+            replacement => Ok(RuntimeLinux { replacement }),
+        }
+    }
+}
+
+impl Default for LinuxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sanitizers::REMOVE;
+    use crate::Sanitizer;
+
+    #[test]
+    fn test_runtime() {
+        let platform = RuntimeLinux::builder().replacement('-').build().unwrap();
+        let iter = platform
+            .filename_sanitizer()
+            .sanitize("a/b\0c\u{202E}".chars());
+
+        assert_iter_eq_str!(iter, "a-b-c-");
+    }
+
+    #[test]
+    fn test_runtime_remove() {
+        let platform = RuntimeLinux::builder().replacement(REMOVE).build().unwrap();
+        let iter = platform.folder_sanitizer().sanitize("a/b\u{FFFE}c".chars());
+
+        assert_iter_eq_str!(iter, "abc");
+    }
+
+    #[test]
+    fn test_relative() {
+        let sanitizer = Linux::<'\u{FFFD}'>::default().filename_sanitizer();
+
+        assert_iter_eq_str!(sanitizer.sanitize_ref("".chars()), "_");
+        assert_iter_eq_str!(sanitizer.sanitize_ref(".".chars()), "._");
+        assert_iter_eq_str!(sanitizer.sanitize_ref("..".chars()), ".._");
+        assert_iter_eq_str!(sanitizer.sanitize_ref("...".chars()), "...");
+
+        let sanitizer = Linux::<REMOVE>::default().folder_sanitizer();
+        assert_iter_eq_str!(sanitizer.sanitize_ref("/".chars()), "_");
+    }
+
+    #[test]
+    fn test_max_length() {
+        let platform: Linux<'\u{FFFD}'> = Linux::default();
+        let iter = platform
+            .filename_sanitizer()
+            .sanitize(core::iter::repeat_n('\u{00E4}', 200));
+
+        assert_eq!(iter.count(), 127);
+    }
+
+    #[test]
+    fn test_build_error() {
+        let result = RuntimeLinux::builder().replacement('/').build();
+        assert_eq!(result, Err(BuildError::Replacement('/')));
+
+        let result = RuntimeLinux::builder().replacement('\n').build();
+        assert_eq!(result, Err(BuildError::Replacement('\n')));
     }
 }
@@ -1,7 +1,159 @@
+// without the `normalization` feature `nfc` and `nfd` return `()`, which is still a valid sanitizer
+#![cfg_attr(not(feature = "normalization"), allow(clippy::unit_arg))]
+
+mod hfs_plus;
 mod linux;
 mod onedrive;
+#[cfg(all(test, feature = "alloc"))]
+mod validators;
 mod windows;
+mod wsl;
 
+pub use hfs_plus::*;
 pub use linux::*;
 pub use onedrive::*;
 pub use windows::*;
+pub use wsl::*;
+
+use core::fmt;
+
+#[cfg(all(test, feature = "alloc"))]
+use crate::sanitizers::is_invisible;
+use crate::sanitizers::Replacer;
+#[cfg(feature = "normalization")]
+use crate::sanitizers::{Form, Normalize};
+
+/// Replaces the characters matched by `M` with the replacement character chosen at runtime,
+/// or removes them if it is `None`.
+type ReplaceWith<M> = Replacer<(M, Option<char>), Option<char>>;
+
+/// The error returned by the builders of the runtime platforms, if a character is not allowed by the platform.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BuildError {
+    /// The replacement character is replaced by the platform itself, like `/` on Linux.
+    Replacement(char),
+    /// The padding character is replaced by the platform or would be removed at the end of a name,
+    /// like `:` or ` ` on Windows.
+    Padding(char),
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Replacement(c) => write!(f, "the replacement character {c:?} is not allowed"),
+            Self::Padding(c) => write!(f, "the padding character {c:?} is not allowed"),
+        }
+    }
+}
+
+impl core::error::Error for BuildError {}
+
+/// The sanitizer returned by `nfc` and `nfd`.
+#[cfg(feature = "normalization")]
+type Normalization = Normalize;
+
+#[cfg(not(feature = "normalization"))]
+type Normalization = ();
+
+/// The normalization to NFC that is applied by the platforms,
+/// without the `normalization` feature this does nothing.
+#[cfg(feature = "normalization")]
+#[must_use]
+const fn nfc() -> Normalize {
+    Normalize::new(Form::Nfc)
+}
+
+#[cfg(not(feature = "normalization"))]
+const fn nfc() {}
+
+/// The normalization to NFD that is applied by the platforms,
+/// without the `normalization` feature this does nothing.
+#[cfg(feature = "normalization")]
+#[must_use]
+const fn nfd() -> Normalize {
+    Normalize::new(Form::Nfd)
+}
+
+#[cfg(not(feature = "normalization"))]
+const fn nfd() {}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::validators::*;
+    use super::*;
+    use crate::sanitizers::REMOVE;
+    use crate::{sanitize_filename, sanitize_folder, Platform};
+
+    use alloc::string::String;
+    use proptest::prelude::*;
+
+    /// Arbitrary names and names made of the characters, which are treated specially by the platforms.
+    fn name() -> impl Strategy<Value = String> {
+        prop_oneof![
+            any::<String>(),
+            "[ ~._<>:\"/\\\\|?*#%&{}\\x00-\\x1F\u{85}\u{200B}\u{202E}\u{FEFF}\u{FFFE}\u{301}\u{338}=eNULCOMLPTnulcom1]{0,16}",
+            prop::sample::select(
+                &[
+                    "", ".", "..", "NUL", "nul", "NUL.txt", "nul.tar.gz", "COM1", "lpt9.", " CON",
+                    "CON.", "con ", "a.", "a ", ".lock", "desktop.ini", "desktop.INI", "~_vti_", "a_VTI_b", "COM\u{B9}",
+                ][..]
+            )
+            .prop_map(String::from),
+        ]
+    }
+
+    /// Checks, that sanitizing is idempotent and the sanitized names are valid.
+    fn check<P: Platform + Copy>(
+        platform: P,
+        name: &str,
+        is_valid: impl Fn(&str, bool) -> bool,
+    ) -> Result<(), TestCaseError> {
+        let filename = sanitize_filename(name, platform);
+        prop_assert_eq!(&sanitize_filename(&filename, platform), &filename);
+        prop_assert!(is_valid(&filename, true), "invalid filename {:?}", filename);
+
+        let folder = sanitize_folder(name, platform);
+        prop_assert_eq!(&sanitize_folder(&folder, platform), &folder);
+        prop_assert!(is_valid(&folder, false), "invalid folder {:?}", folder);
+
+        Ok(())
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(1024))]
+
+        #[test]
+        fn test_linux(name in name()) {
+            check(Linux::<'_'>::default(), &name, |name, _| is_linux_valid(name))?;
+            check(Linux::<REMOVE>::default(), &name, |name, _| is_linux_valid(name))?;
+        }
+
+        #[test]
+        fn test_windows(name in name()) {
+            check(Windows::<'_', '_'>::default(), &name, |name, _| is_windows_valid(name))?;
+            check(Windows::<REMOVE, '_'>::default(), &name, |name, _| is_windows_valid(name))?;
+        }
+
+        #[test]
+        fn test_onedrive(name in name()) {
+            check(OneDrive::<'_', '_'>::default(), &name, is_onedrive_valid)?;
+            check(OneDrive::<REMOVE, '_'>::default(), &name, is_onedrive_valid)?;
+        }
+
+        #[test]
+        fn test_hfs_plus(name in name()) {
+            check(HfsPlus::<'_'>::default(), &name, |name, _| is_hfs_plus_valid(name))?;
+            check(HfsPlus::<REMOVE>::default(), &name, |name, _| is_hfs_plus_valid(name))?;
+        }
+
+        /// WSL is not idempotent, because the characters in the mapped private use range are replaced.
+        #[test]
+        fn test_wsl(name in name()) {
+            let platform = Wsl::<'_'>::default();
+            let filename = sanitize_filename(&name, platform);
+            prop_assert!(is_wsl_valid(&filename), "invalid filename {:?}", filename);
+            let folder = sanitize_folder(&name, platform);
+            prop_assert!(is_wsl_valid(&folder), "invalid folder {:?}", folder);
+        }
+    }
+}
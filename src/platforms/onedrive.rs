@@ -1,27 +1,55 @@
+use core::str::Chars;
+
+use super::{BuildError, ReplaceWith};
+use crate::constant_arrays;
 use crate::sanitizer_ext::SanitizerExt;
-use crate::sanitizers::Replacer;
-use crate::Platform;
-use crate::{constant_arrays, Sanitizer};
+use crate::sanitizers::{
+    is_invisible, replacement, replacement_for, InfixPadder, PrefixStripper, Replacer,
+    RuntimeInvisible, RuntimePadder, Then, REMOVE,
+};
+use crate::{AsciiSet, Platform};
+
+/// The sanitizer for filenames and folders on `OneDrive`.
+type OneDriveSanitizer = Then<
+    Then<
+        Then<Then<ReplaceWith<AsciiSet>, RuntimeInvisible>, PrefixStripper<fn(char) -> bool>>,
+        InfixPadder<'static>,
+    >,
+    RuntimePadder<Chars<'static>, 27>,
+>;
+
+constant_arrays! {
+    /// <https://support.microsoft.com/en-us/office/restrictions-and-limitations-in-onedrive-and-sharepoint-64883a5d-228e-48f5-b3d2-eb39e07630fa>
+    const FORBIDDEN_CHARACTERS_FOLDER: [char; _] = ['"', '*', ':', '<', '>', '?', '/', '\\', '|'];
+    /// <https://support.microsoft.com/en-us/office/restrictions-and-limitations-in-onedrive-and-sharepoint-64883a5d-228e-48f5-b3d2-eb39e07630fa>
+    const FORBIDDEN_CHARACTERS_FILE: [char; _] = [
+        '~', '"', '#', '%', '&', '*', ':', '<', '>', '?', '/', '\\', '{', '|', '}',
+    ];
+    /// The empty name can not be used either, `_vti_` is padded anywhere in the name.
+    const RESERVED_FILENAMES: [&'static str; _] = [
+        "", ".lock", "CON", "PRN", "AUX", "NUL",
+        "COM0", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8", "COM9",
+        "LPT0", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+        "desktop.ini"
+    ];
+}
 
+const FORBIDDEN_SET_FOLDER: AsciiSet = AsciiSet::from_chars(&FORBIDDEN_CHARACTERS_FOLDER);
+const FORBIDDEN_SET_FILE: AsciiSet = AsciiSet::from_chars(&FORBIDDEN_CHARACTERS_FILE);
+
+/// Returns true if the character is replaced in filenames, which forbid more characters than folders.
+const fn is_replaced(c: char) -> bool {
+    FORBIDDEN_SET_FILE.contains(c) || is_invisible(c)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct OneDrive<const RP: char, const P: char> {}
 
 impl<const RP: char, const P: char> OneDrive<RP, P> {
-    constant_arrays! {
-        /// https://support.microsoft.com/en-us/office/restrictions-and-limitations-in-onedrive-and-sharepoint-64883a5d-228e-48f5-b3d2-eb39e07630fa
-        const FORBIDDEN_CHARACTERS_FOLDER: [char; _] = ['"', '*', ':', '<', '>', '?', '/', '\\', '|'];
-        /// https://support.microsoft.com/en-us/office/restrictions-and-limitations-in-onedrive-and-sharepoint-64883a5d-228e-48f5-b3d2-eb39e07630fa
-        const FORBIDDEN_CHARACTERS_FILE: [char; _] = [
-            '~', '"', '#', '%', '&', '*', ':', '<', '>', '?', '/', '\\', '{', '|', '}',
-        ];
-        const RESERVED_FILENAMES: [&'static str; _] = [
-            ".lock", "CON", "PRN", "AUX", "NUL",
-            "COM0", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8", "COM9",
-            "LPT0", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
-            // TODO: _vti_ cannot appear anywhere in the filename!
-            "_vti_",
-            "desktop.ini"
-        ];
-    }
+    const RUNTIME: RuntimeOneDrive = RuntimeOneDrive {
+        replacement: replacement::<RP>(),
+        padding: P,
+    };
 }
 
 impl<const RP: char, const P: char> Default for OneDrive<RP, P> {
@@ -31,21 +59,190 @@ impl<const RP: char, const P: char> Default for OneDrive<RP, P> {
 }
 
 impl<const RP: char, const P: char> Platform for OneDrive<RP, P> {
-    type FilenameSanitizer<'a> = impl Sanitizer + 'a;
-    type FolderSanitizer<'a> = impl Sanitizer + 'a;
+    type FilenameSanitizer<'a> = OneDriveSanitizer;
+    type FolderSanitizer<'a> = OneDriveSanitizer;
+
+    fn filename_sanitizer(&self) -> Self::FilenameSanitizer<'_> {
+        Self::RUNTIME.filename_sanitizer()
+    }
+
+    fn folder_sanitizer(&self) -> Self::FolderSanitizer<'_> {
+        Self::RUNTIME.folder_sanitizer()
+    }
+}
+
+/// The same rules as [`OneDrive`], but the replacement and padding characters are chosen at runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RuntimeOneDrive {
+    replacement: Option<char>,
+    padding: char,
+}
+
+impl RuntimeOneDrive {
+    #[must_use]
+    pub const fn builder() -> OneDriveBuilder {
+        OneDriveBuilder::new()
+    }
+
+    /// Pads `_vti_` as `_vt_i_`, which can not appear anywhere in a name, splitting it after `_vt`
+    /// makes sure, that the padding can not form it again.
+    const fn infix_padder(self) -> InfixPadder<'static> {
+        InfixPadder::new("_vt", "i_", self.padding)
+    }
+
+    /// Pads the reserved filenames compared case-insensitive, like `desktop.INI`.
+    fn reserved_padder(self) -> RuntimePadder<Chars<'static>, 27> {
+        RuntimePadder::new(RESERVED_FILENAMES.map(str::chars), self.padding, None)
+            .ignore_ascii_case()
+    }
+}
+
+impl Default for RuntimeOneDrive {
+    fn default() -> Self {
+        OneDrive::<'\u{FFFD}', '_'>::RUNTIME
+    }
+}
+
+impl Platform for RuntimeOneDrive {
+    type FilenameSanitizer<'a> = OneDriveSanitizer;
+    type FolderSanitizer<'a> = OneDriveSanitizer;
 
     fn filename_sanitizer(&self) -> Self::FilenameSanitizer<'_> {
-        Replacer::from(Self::FORBIDDEN_CHARACTERS_FILE.map(|c| (c, RP)))
-            .strip_prefix(|c| c == '~')
-            .strip_prefix(char::is_whitespace)
-            .padding::<P, 27>(Self::RESERVED_FILENAMES)
+        Replacer::from((FORBIDDEN_SET_FILE, self.replacement))
+            .then(RuntimeInvisible::new(self.replacement.unwrap_or(REMOVE)))
+            // stripped together, because removing one could expose the other
+            .strip_prefix::<fn(char) -> bool>(|c| c == '~' || c.is_whitespace())
+            .then(self.infix_padder())
+            .then(self.reserved_padder())
     }
 
     fn folder_sanitizer(&self) -> Self::FolderSanitizer<'_> {
-        Replacer::from(Self::FORBIDDEN_CHARACTERS_FOLDER.map(|c| (c, RP)))
-            .strip_prefix(|c| c == '~')
-            .strip_prefix(char::is_whitespace)
+        Replacer::from((FORBIDDEN_SET_FOLDER, self.replacement))
+            .then(RuntimeInvisible::new(self.replacement.unwrap_or(REMOVE)))
+            // stripped together, because removing one could expose the other
+            .strip_prefix::<fn(char) -> bool>(|c| c == '~' || c.is_whitespace())
             // those are forbidden for folders as well:
-            .padding::<P, 27>(Self::RESERVED_FILENAMES)
+            .then(self.infix_padder())
+            .then(self.reserved_padder())
+    }
+}
+
+/// Configures a [`RuntimeOneDrive`], by default the replacement character is `U+FFFD`
+/// and the padding character is `_`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OneDriveBuilder {
+    replacement: Option<char>,
+    padding: char,
+}
+
+impl OneDriveBuilder {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            replacement: Some('\u{FFFD}'),
+            padding: '_',
+        }
+    }
+
+    /// Sets the replacement character, [`REMOVE`](crate::sanitizers::REMOVE) removes the characters instead.
+    #[must_use]
+    pub const fn replacement(mut self, replacement: char) -> Self {
+        self.replacement = replacement_for(replacement);
+        self
+    }
+
+    /// Sets the character, which is appended to reserved filenames like `desktop.ini`.
+    #[must_use]
+    pub const fn padding(mut self, padding: char) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Returns the platform, or an error if one of the characters is not allowed on `OneDrive`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BuildError::Replacement`] for characters like `#` or `?` and [`BuildError::Padding`]
+    /// for those and trailing characters, which are removed by Windows, like `.` or ` `.
+    pub fn build(self) -> Result<RuntimeOneDrive, BuildError> {
+        match (self.replacement, self.padding) {
+            (Some(c), _) if is_replaced(c) => Err(BuildError::Replacement(c)),
+            (_, c) if is_replaced(c) || c.is_control() || c == '.' || c.is_whitespace() => {
+                Err(BuildError::Padding(c))
+            }
+            (replacement, padding) => Ok(RuntimeOneDrive {
+                replacement,
+                padding,
+            }),
+        }
+    }
+}
+
+impl Default for OneDriveBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Sanitizer;
+
+    #[test]
+    fn test_runtime() {
+        let platform = RuntimeOneDrive::builder()
+            .replacement('-')
+            .padding('+')
+            .build()
+            .unwrap();
+
+        let iter = platform.filename_sanitizer().sanitize("a#b".chars());
+        assert_iter_eq_str!(iter, "a-b");
+
+        let iter = platform.folder_sanitizer().sanitize("~desktop.ini".chars());
+        assert_iter_eq_str!(iter, "desktop.ini+");
+    }
+
+    #[test]
+    fn test_reserved_case() {
+        let platform: OneDrive<'\u{FFFD}', '_'> = OneDrive::default();
+
+        let iter = platform
+            .filename_sanitizer()
+            .sanitize("desktop.INI".chars());
+        assert_iter_eq_str!(iter, "desktop.INI_");
+
+        let iter = platform.folder_sanitizer().sanitize("Con".chars());
+        assert_iter_eq_str!(iter, "Con_");
+    }
+
+    #[test]
+    fn test_reserved_anywhere() {
+        let platform: OneDrive<'\u{FFFD}', '_'> = OneDrive::default();
+
+        let iter = platform.filename_sanitizer().sanitize("a_vti_b".chars());
+        assert_iter_eq_str!(iter, "a_vt_i_b");
+
+        let iter = platform.folder_sanitizer().sanitize("_VTI_".chars());
+        assert_iter_eq_str!(iter, "_VT_I_");
+
+        let iter = platform.filename_sanitizer().sanitize("".chars());
+        assert_iter_eq_str!(iter, "_");
+
+        // the stripped prefix can not leave an empty name
+        let iter = platform.folder_sanitizer().sanitize("~ ".chars());
+        assert_iter_eq_str!(iter, "_");
+    }
+
+    #[test]
+    fn test_build_error() {
+        let result = RuntimeOneDrive::builder().replacement('#').build();
+        assert_eq!(result, Err(BuildError::Replacement('#')));
+
+        for c in ['~', '\0', '.', ' '] {
+            let result = RuntimeOneDrive::builder().padding(c).build();
+            assert_eq!(result, Err(BuildError::Padding(c)));
+        }
     }
 }
@@ -0,0 +1,147 @@
+//! Checks, whether a name is valid on a platform, which are written from the rules of the platforms
+//! instead of the sanitizers, so those can be tested against them.
+//!
+//! The property tests and the fuzz targets share this file, so it only uses `is_invisible`
+//! from the parent module.
+
+use super::is_invisible;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/fileio/naming-a-file>
+const WINDOWS_RESERVED: [&str; 30] = [
+    "CON",
+    "PRN",
+    "AUX",
+    "NUL",
+    "COM0",
+    "COM1",
+    "COM2",
+    "COM3",
+    "COM4",
+    "COM5",
+    "COM6",
+    "COM7",
+    "COM8",
+    "COM9",
+    "LPT0",
+    "LPT1",
+    "LPT2",
+    "LPT3",
+    "LPT4",
+    "LPT5",
+    "LPT6",
+    "LPT7",
+    "LPT8",
+    "LPT9",
+    "COM\u{B9}",
+    "COM\u{B2}",
+    "COM\u{B3}",
+    "LPT\u{B9}",
+    "LPT\u{B2}",
+    "LPT\u{B3}",
+];
+
+/// <https://support.microsoft.com/en-us/office/restrictions-and-limitations-in-onedrive-and-sharepoint-64883a5d-228e-48f5-b3d2-eb39e07630fa>
+const ONEDRIVE_RESERVED: [&str; 26] = [
+    ".lock",
+    "CON",
+    "PRN",
+    "AUX",
+    "NUL",
+    "COM0",
+    "COM1",
+    "COM2",
+    "COM3",
+    "COM4",
+    "COM5",
+    "COM6",
+    "COM7",
+    "COM8",
+    "COM9",
+    "LPT0",
+    "LPT1",
+    "LPT2",
+    "LPT3",
+    "LPT4",
+    "LPT5",
+    "LPT6",
+    "LPT7",
+    "LPT8",
+    "LPT9",
+    "desktop.ini",
+];
+
+/// The noncharacters like `U+FFFE` are replaced by the platforms, even if they are allowed.
+fn is_noncharacter(c: char) -> bool {
+    matches!(c, '\u{FDD0}'..='\u{FDEF}') || u32::from(c) & 0xFFFE == 0xFFFE
+}
+
+/// Linux forbids `/` and `\0`, the names `.` and `..` refer to directories
+/// and a name has at most 255 bytes.
+pub fn is_linux_valid(name: &str) -> bool {
+    !matches!(name, "" | "." | "..")
+        && name.len() <= 255
+        && !name
+            .chars()
+            .any(|c| c == '/' || c.is_control() || is_invisible(c) || is_noncharacter(c))
+}
+
+/// Windows forbids `<>:"/\|?*` and the control characters, removes trailing dots and spaces
+/// and reserves the device names case-insensitive, even with an extension like `nul.txt`.
+/// A name has at most 255 UTF-16 code units.
+pub fn is_windows_valid(name: &str) -> bool {
+    let stem = name.split('.').next().unwrap_or(name);
+
+    !name.is_empty()
+        && !name
+            .chars()
+            .any(|c| c.is_control() || is_invisible(c) || is_noncharacter(c))
+        && !name.contains(['<', '>', ':', '"', '/', '\\', '|', '?', '*'])
+        && !name.starts_with(char::is_whitespace)
+        && !name.ends_with(['.', ' '])
+        && !WINDOWS_RESERVED
+            .iter()
+            .any(|reserved| reserved.eq_ignore_ascii_case(stem))
+        && name.encode_utf16().count() <= 255
+}
+
+/// `OneDrive` forbids more characters in filenames than in folders, a leading `~`,
+/// the empty name and `_vti_` anywhere in the name and reserves the names case-insensitive.
+pub fn is_onedrive_valid(name: &str, is_file: bool) -> bool {
+    let forbidden: &[char] = if is_file {
+        &[
+            '~', '"', '#', '%', '&', '*', ':', '<', '>', '?', '/', '\\', '{', '|', '}',
+        ]
+    } else {
+        &['"', '*', ':', '<', '>', '?', '/', '\\', '|']
+    };
+
+    !name.is_empty()
+        && !name.contains(forbidden)
+        && !name
+            .as_bytes()
+            .windows(5)
+            .any(|window| window.eq_ignore_ascii_case(b"_vti_"))
+        && !name.chars().any(is_invisible)
+        && !name.starts_with(|c: char| c == '~' || c.is_whitespace())
+        && !ONEDRIVE_RESERVED
+            .iter()
+            .any(|reserved| reserved.eq_ignore_ascii_case(name))
+}
+
+/// HFS+ forbids `/` and `:`, which is shown as `/` by the Finder, the names `.` and `..`
+/// refer to directories and a name has at most 255 UTF-16 code units.
+pub fn is_hfs_plus_valid(name: &str) -> bool {
+    !matches!(name, "" | "." | "..")
+        && name.encode_utf16().count() <= 255
+        && !name.contains(['/', ':', '\0'])
+        && !name.chars().any(is_invisible)
+}
+
+/// WSL stores the characters, which are forbidden on Windows, in the private use area,
+/// so they must not appear in the name.
+pub fn is_wsl_valid(name: &str) -> bool {
+    !name.contains(['/', '\0', '<', '>', ':', '"', '\\', '|', '?', '*'])
+        && !name
+            .chars()
+            .any(|c| ('\x01'..='\x1F').contains(&c) || is_invisible(c))
+}
@@ -1,8 +1,68 @@
-use crate::sanitizer::Sanitizer;
+use core::str::Chars;
+
+use super::{nfc, BuildError, Normalization, ReplaceWith};
 use crate::sanitizer_ext::SanitizerExt;
-use crate::sanitizers::Replacer;
-use crate::{constant_arrays, Platform};
+use crate::sanitizers::{
+    is_invisible, replacement, replacement_for, Categories, Escaper, ExtensionAction,
+    ExtensionPolicy, PrefixStripper, Replacer, RuntimeGeneralCategory, RuntimeInvisible,
+    RuntimePadder, Then, TrailingReplacer, Truncate, Unescaper, REMOVE,
+};
+use crate::{constant_arrays, AsciiSet, Platform};
+
+/// The sanitizer for filenames and folders on Windows.
+type WindowsSanitizer = Then<
+    Then<
+        Then<
+            Then<
+                Then<
+                    Then<Then<ReplaceWith<AsciiSet>, RuntimeInvisible>, RuntimeGeneralCategory>,
+                    Normalization,
+                >,
+                PrefixStripper<fn(char) -> bool>,
+            >,
+            RuntimePadder<Chars<'static>, 30>,
+        >,
+        Truncate,
+    >,
+    TrailingReplacer<fn(char) -> bool>,
+>;
+
+// https://docs.microsoft.com/en-us/windows/win32/fileio/naming-a-file
+
+constant_arrays! {
+    /// Reserved Characters on Windows
+    const RESERVED_CHARACTERS: [char; _] = ['<', '>', ':', '"', '/', '\\', '|', '?', '*', '\0'];
+    /// These filenames are forbidden (including filename extension)
+    ///
+    /// For example the following filenames are invalid:
+    /// - `NUL`
+    /// - `NUL.txt`
+    /// - `NUL.txt.txt`
+    /// - `COM\u{B9}`
+    const RESERVED_FILENAMES: [&'static str; _] = [
+        "CON", "PRN", "AUX", "NUL",
+        "COM0", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8", "COM9",
+        "LPT0", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+        // the superscript digits are treated like the digits
+        "COM\u{B9}", "COM\u{B2}", "COM\u{B3}", "LPT\u{B9}", "LPT\u{B2}", "LPT\u{B3}"
+    ];
+}
+
+const RESERVED_SET: AsciiSet = AsciiSet::from_chars(&RESERVED_CHARACTERS);
+
+const MAX_LENGTH: usize = 255;
 
+/// Returns true if Windows removes the character at the end of a name.
+const fn is_trailing(c: char) -> bool {
+    c == '.' || c == ' '
+}
+
+/// Returns true if the character is replaced by the platform.
+fn is_replaced(c: char) -> bool {
+    RESERVED_SET.contains(c) || is_invisible(c) || Categories::DEFAULT.matches(c)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Windows<const RP: char, const P: char> {}
 
 impl<const RP: char, const P: char> Default for Windows<RP, P> {
@@ -11,70 +71,215 @@ impl<const RP: char, const P: char> Default for Windows<RP, P> {
     }
 }
 
-// https://docs.microsoft.com/en-us/windows/win32/fileio/naming-a-file
-
 impl<const RP: char, const P: char> Windows<RP, P> {
     constant_arrays! {
-        /// Reserved Characters on Windows
-        const RESERVED_CHARACTERS: [char; _] = ['<', '>', ':', '"', '/', '\\', '|', '?', '*', '\0'];
-        /// These filenames are forbidden (including filename extension)
-        ///
-        /// For example the following filenames are invalid:
-        /// - `NUL`
-        /// - `NUL.txt`
-        /// - `NUL.txt.txt`
-        const RESERVED_FILENAMES: [&'static str; _] = [
-            "CON", "PRN", "AUX", "NUL",
-            "COM0", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8", "COM9",
-            "LPT0", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9"
+        /// Extensions of files, that are executed when opened
+        pub const EXECUTABLE_EXTENSIONS: [&'static str; _] = [
+            "exe", "com", "scr", "pif", "bat", "cmd", "lnk", "msi", "msp", "cpl", "hta",
+            "js", "jse", "vbs", "vbe", "wsf", "wsh", "ps1", "reg", "jar"
         ];
     }
+
+    /// The maximum length of a file or folder name in UTF-16 code units
+    pub const MAX_LENGTH: usize = MAX_LENGTH;
+
+    /// Returns a reversible sanitizer, which escapes the reserved characters, control characters,
+    /// invisible characters, reserved filenames and trailing dots or spaces.
+    ///
+    /// The original name can be restored with [`Windows::unescaper`].
+    #[must_use]
+    pub fn escaper(&self) -> Escaper<fn(char) -> bool, Chars<'static>, 30> {
+        Escaper::new(
+            |c| RESERVED_SET.contains(c) || c.is_control() || is_invisible(c),
+            RESERVED_FILENAMES.map(str::chars),
+        )
+    }
+
+    /// Returns a sanitizer, which applies the `action` to names with an [executable extension](Self::EXECUTABLE_EXTENSIONS),
+    /// like `photo.jpg.exe`.
+    #[must_use]
+    pub const fn extension_policy<'a>(
+        &self,
+        action: ExtensionAction<'a>,
+    ) -> ExtensionPolicy<'a, 20> {
+        ExtensionPolicy::new(Self::EXECUTABLE_EXTENSIONS, action)
+    }
+
+    /// Returns a sanitizer, which restores the original name from the output of [`Windows::escaper`].
+    #[must_use]
+    pub fn unescaper(&self) -> Unescaper {
+        Unescaper::default()
+    }
+
+    const RUNTIME: RuntimeWindows = RuntimeWindows {
+        replacement: replacement::<RP>(),
+        padding: P,
+    };
 }
 
 impl<const RP: char, const P: char> Platform for Windows<RP, P> {
-    type FilenameSanitizer<'a> = impl Sanitizer + 'a;
-    type FolderSanitizer<'a> = impl Sanitizer + 'a;
+    type FilenameSanitizer<'a> = WindowsSanitizer;
+    type FolderSanitizer<'a> = WindowsSanitizer;
+
+    fn filename_sanitizer(&self) -> Self::FilenameSanitizer<'_> {
+        Self::RUNTIME.filename_sanitizer()
+    }
+
+    fn folder_sanitizer(&self) -> Self::FolderSanitizer<'_> {
+        Self::RUNTIME.folder_sanitizer()
+    }
+}
+
+/// The same rules as [`Windows`], but the replacement and padding characters are chosen at runtime,
+/// for example from a settings file.
+///
+/// ```
+/// use path_sanitizer::platforms::RuntimeWindows;
+/// use path_sanitizer::{Platform, Sanitizer};
+///
+/// let platform = RuntimeWindows::builder().replacement('-').padding('_').build().unwrap();
+/// let sanitizer = platform.filename_sanitizer();
+///
+/// assert!(sanitizer.sanitize_ref("NUL".chars()).eq("NUL_".chars()));
+/// assert!(sanitizer.sanitize_ref("a?b".chars()).eq("a-b".chars()));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RuntimeWindows {
+    replacement: Option<char>,
+    padding: char,
+}
+
+impl RuntimeWindows {
+    #[must_use]
+    pub const fn builder() -> WindowsBuilder {
+        WindowsBuilder::new()
+    }
+
+    /// Pads the reserved filenames compared case-insensitive, even if they are followed by an extension.
+    fn reserved_padder(self) -> RuntimePadder<Chars<'static>, 30> {
+        RuntimePadder::new(RESERVED_FILENAMES.map(str::chars), self.padding, Some('.'))
+            .ignore_ascii_case()
+    }
+}
+
+impl Default for RuntimeWindows {
+    fn default() -> Self {
+        Windows::<'\u{FFFD}', '_'>::RUNTIME
+    }
+}
+
+impl Platform for RuntimeWindows {
+    type FilenameSanitizer<'a> = WindowsSanitizer;
+    type FolderSanitizer<'a> = WindowsSanitizer;
 
     fn filename_sanitizer(&self) -> Self::FilenameSanitizer<'_> {
-        // TODO: this should also padd something like: NUL.txt to NUL_.txt
-        // TODO: remove trailing dots and trailing spaces (forbidden)
-        // TODO: trailing spaces can be removed by deduplicating them first and then only removing the last character?
         // replace the explicitly forbidden characters:
-        Replacer::from(Self::RESERVED_CHARACTERS.map(|c| (c, RP)))
-            // replace control characters in the filename
-            .replace_control::<RP>()
+        Replacer::from((RESERVED_SET, self.replacement))
+            // replace bidirectional formatting and invisible characters
+            .then(RuntimeInvisible::new(self.replacement.unwrap_or(REMOVE)))
+            // replace control characters and noncharacters in the filename
+            .then(RuntimeGeneralCategory::new(
+                Categories::DEFAULT,
+                self.replacement.unwrap_or(REMOVE),
+            ))
+            // normalize after removing characters, which could change the normalization
+            .then(nfc())
             // remove leading whitespace from the filename
-            .strip_prefix(char::is_whitespace)
-            // padd forbidden filenames
-            .padding::<P, 24>(Self::RESERVED_FILENAMES)
+            .strip_prefix::<fn(char) -> bool>(char::is_whitespace)
+            // padd forbidden filenames, also with an extension like `NUL.txt`
+            .then(self.reserved_padder())
+            .truncate_utf16(MAX_LENGTH)
+            // Windows removes trailing dots and spaces, which could expose a reserved name,
+            // this is done last, so the truncation can not expose them again
+            .replace_trailing::<fn(char) -> bool>(is_trailing, self.padding)
     }
 
     fn folder_sanitizer(&self) -> Self::FolderSanitizer<'_> {
-        Replacer::from(Self::RESERVED_CHARACTERS.map(|c| (c, RP)))
-            // replace control characters
-            .replace_control::<RP>()
+        Replacer::from((RESERVED_SET, self.replacement))
+            // replace bidirectional formatting and invisible characters
+            .then(RuntimeInvisible::new(self.replacement.unwrap_or(REMOVE)))
+            // replace control characters and noncharacters
+            .then(RuntimeGeneralCategory::new(
+                Categories::DEFAULT,
+                self.replacement.unwrap_or(REMOVE),
+            ))
+            .then(nfc())
             // remove leading whitespace
-            .strip_prefix(char::is_whitespace)
+            .strip_prefix::<fn(char) -> bool>(char::is_whitespace)
             // NOTE: those are not allowed in folders either
-            .padding::<P, 24>(Self::RESERVED_FILENAMES)
+            .then(self.reserved_padder())
+            .truncate_utf16(MAX_LENGTH)
+            .replace_trailing::<fn(char) -> bool>(is_trailing, self.padding)
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Configures a [`RuntimeWindows`], by default the replacement character is `U+FFFD`
+/// and the padding character is `_`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WindowsBuilder {
+    replacement: Option<char>,
+    padding: char,
+}
 
-    macro_rules! assert_iter_eq_str {
-        ( $iter:expr, $string:expr ) => {{
-            let mut chars = $string.chars();
+impl WindowsBuilder {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            replacement: Some('\u{FFFD}'),
+            padding: '_',
+        }
+    }
+
+    /// Sets the replacement character, [`REMOVE`](crate::sanitizers::REMOVE) removes the characters instead.
+    #[must_use]
+    pub const fn replacement(mut self, replacement: char) -> Self {
+        self.replacement = replacement_for(replacement);
+        self
+    }
+
+    /// Sets the character, which is appended to reserved filenames like `NUL`.
+    #[must_use]
+    pub const fn padding(mut self, padding: char) -> Self {
+        self.padding = padding;
+        self
+    }
 
-            for c in $iter {
-                assert_eq!(Some(c), chars.next());
+    /// Returns the platform, or an error if one of the characters is not allowed on Windows.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BuildError::Replacement`] for characters like `:` or `\n` and [`BuildError::Padding`]
+    /// for those, trailing characters, which are removed by Windows, like `.` or ` `,
+    /// and characters, which need two UTF-16 code units.
+    pub fn build(self) -> Result<RuntimeWindows, BuildError> {
+        match (self.replacement, self.padding) {
+            (Some(c), _) if is_replaced(c) => Err(BuildError::Replacement(c)),
+            // the padding replaces trailing characters, so it must not change the length
+            (_, c)
+                if is_replaced(c) || is_trailing(c) || c.is_whitespace() || c.len_utf16() != 1 =>
+            {
+                Err(BuildError::Padding(c))
             }
+            (replacement, padding) => Ok(RuntimeWindows {
+                replacement,
+                padding,
+            }),
+        }
+    }
+}
 
-            assert_eq!(None, chars.next());
-        }};
+impl Default for WindowsBuilder {
+    fn default() -> Self {
+        Self::new()
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoding::{sanitize_utf16, DecodeMode};
+    use crate::sanitizers::REMOVE;
+    use crate::Sanitizer;
 
     #[test]
     fn test_filename_reserved() {
@@ -83,4 +288,179 @@ mod tests {
 
         assert_iter_eq_str!(iter, "NUL_");
     }
+
+    #[test]
+    fn test_filename_reserved_case_and_extension() {
+        let sanitizer = Windows::<'\u{FFFD}', '_'>::default().filename_sanitizer();
+
+        assert_iter_eq_str!(sanitizer.sanitize_ref("nul".chars()), "nul_");
+        assert_iter_eq_str!(sanitizer.sanitize_ref("NUL.txt".chars()), "NUL_.txt");
+        assert_iter_eq_str!(
+            sanitizer.sanitize_ref("com9.tar.gz".chars()),
+            "com9_.tar.gz"
+        );
+        assert_iter_eq_str!(sanitizer.sanitize_ref("CONSOLE".chars()), "CONSOLE");
+        assert_iter_eq_str!(sanitizer.sanitize_ref("COM\u{B9}".chars()), "COM\u{B9}_");
+        assert_iter_eq_str!(
+            sanitizer.sanitize_ref("lpt\u{B3}.txt".chars()),
+            "lpt\u{B3}_.txt"
+        );
+    }
+
+    #[test]
+    fn test_filename_trailing() {
+        let sanitizer = Windows::<'\u{FFFD}', '_'>::default().filename_sanitizer();
+
+        assert_iter_eq_str!(sanitizer.sanitize_ref("a.".chars()), "a_");
+        assert_iter_eq_str!(sanitizer.sanitize_ref("a ".chars()), "a_");
+        assert_iter_eq_str!(sanitizer.sanitize_ref("CON.".chars()), "CON__");
+        assert_iter_eq_str!(sanitizer.sanitize_ref("con ".chars()), "con_");
+        assert_iter_eq_str!(sanitizer.sanitize_ref("..".chars()), "._");
+        assert_iter_eq_str!(sanitizer.sanitize_ref(" ".chars()), "_");
+        assert_iter_eq_str!(sanitizer.sanitize_ref("".chars()), "_");
+    }
+
+    #[cfg(feature = "normalization")]
+    #[test]
+    fn test_filename_nfc() {
+        let platform: Windows<'\u{FFFD}', '_'> = Windows::default();
+        let iter = platform
+            .filename_sanitizer()
+            .sanitize("cafe\u{0301}?".chars());
+
+        assert_iter_eq_str!(iter, "caf\u{00E9}\u{FFFD}");
+    }
+
+    #[test]
+    fn test_filename_bidi_override() {
+        let platform: Windows<'\u{FFFD}', '_'> = Windows::default();
+        let iter = platform
+            .filename_sanitizer()
+            .sanitize("invoice\u{202E}fdp.exe".chars());
+
+        assert_iter_eq_str!(iter, "invoice\u{FFFD}fdp.exe");
+    }
+
+    #[test]
+    fn test_filename_remove() {
+        let platform: Windows<REMOVE, '_'> = Windows::default();
+        let iter = platform.filename_sanitizer().sanitize("a<b>\x07c?".chars());
+
+        assert_iter_eq_str!(iter, "abc");
+    }
+
+    #[test]
+    fn test_filename_noncharacter() {
+        let platform: Windows<'_', '_'> = Windows::default();
+        let iter = platform
+            .filename_sanitizer()
+            .sanitize("a\u{85}b\u{FFFE}c\u{FDD0}.txt".chars());
+
+        assert_iter_eq_str!(iter, "a_b_c_.txt");
+    }
+
+    #[test]
+    fn test_filename_reuse() {
+        let platform: Windows<'_', '_'> = Windows::default();
+        let sanitizer = platform.filename_sanitizer();
+
+        assert_iter_eq_str!(sanitizer.sanitize_ref("a?".chars()), "a_");
+        assert_iter_eq_str!(sanitizer.sanitize_ref("NUL".chars()), "NUL_");
+        assert_iter_eq_str!(sanitizer.sanitize_ref(" b".chars()), "b");
+    }
+
+    #[test]
+    fn test_runtime() {
+        let platform = RuntimeWindows::builder()
+            .replacement(REMOVE)
+            .padding('-')
+            .build()
+            .unwrap();
+
+        let iter = platform
+            .filename_sanitizer()
+            .sanitize(" a<b>\x07c?".chars());
+        assert_iter_eq_str!(iter, "abc");
+
+        let iter = platform.folder_sanitizer().sanitize("LPT1".chars());
+        assert_iter_eq_str!(iter, "LPT1-");
+    }
+
+    #[test]
+    fn test_build_error() {
+        for c in [':', '?', '/', '\\', '\u{202E}'] {
+            let result = RuntimeWindows::builder().replacement(c).build();
+            assert_eq!(result, Err(BuildError::Replacement(c)));
+        }
+
+        for c in [':', '\0', '.', ' ', '\u{3000}'] {
+            let result = RuntimeWindows::builder().padding(c).build();
+            assert_eq!(result, Err(BuildError::Padding(c)));
+        }
+    }
+
+    #[test]
+    fn test_extension_policy() {
+        let platform: Windows<'\u{FFFD}', '_'> = Windows::default();
+        let policy = platform.extension_policy(ExtensionAction::Append(".txt"));
+
+        assert_iter_eq_str!(
+            policy.sanitize("photo.jpg.exe".chars()),
+            "photo.jpg.exe.txt"
+        );
+        assert_eq!(policy.check("readme.txt.lnk"), Some("lnk"));
+    }
+
+    #[test]
+    fn test_escaper_roundtrip() {
+        let platform: Windows<'\u{FFFD}', '_'> = Windows::default();
+        let string = "nul.<50%>\t\\?. ";
+
+        let escaped = platform.escaper().sanitize(string.chars());
+        assert_iter_eq_str!(escaped, "nu%6C.%3C50%25%3E%09%5C%3F.%20");
+
+        let escaped = platform.escaper().sanitize(string.chars());
+        let iter = platform.unescaper().sanitize(escaped);
+        assert_iter_eq_str!(iter, string);
+    }
+
+    #[test]
+    fn test_filename_utf16() {
+        let platform: Windows<'_', '_'> = Windows::default();
+        // `a?` followed by an unpaired high surrogate
+        let units = [0x61, 0x3F, 0xD83D];
+
+        let mut iter = sanitize_utf16(
+            platform.filename_sanitizer(),
+            units,
+            DecodeMode::PassThrough,
+        );
+        assert_eq!(iter.next(), Some(0x61));
+        assert_eq!(iter.next(), Some(0x5F));
+        assert_eq!(iter.next(), Some(0xD83D));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_filename_max_length() {
+        let platform: Windows<'_', '_'> = Windows::default();
+        let iter = platform
+            .filename_sanitizer()
+            .sanitize(core::iter::repeat_n('\u{1F604}', 200));
+
+        assert_eq!(iter.count(), 127);
+    }
+
+    #[test]
+    fn test_filename_max_length_utf16() {
+        let platform: Windows<'_', '_'> = Windows::default();
+        let units = core::iter::repeat_n(0xDC00, 300);
+        let iter = sanitize_utf16(
+            platform.filename_sanitizer(),
+            units,
+            DecodeMode::PassThrough,
+        );
+
+        assert_eq!(iter.count(), 255);
+    }
 }
@@ -0,0 +1,113 @@
+use super::linux::FORBIDDEN_SET;
+use super::ReplaceWith;
+use crate::sanitizer_ext::SanitizerExt;
+use crate::sanitizers::{replacement, Invisible, Replacer, Then, PRIVATE_USE_OFFSET};
+use crate::{constant_arrays, Platform, Sorted};
+
+/// The sanitizer for filenames and folders on WSL.
+type WslSanitizer<const RP: char> =
+    Then<Then<ReplaceWith<fn(char) -> bool>, Replacer<Sorted<[(char, char); 39]>>>, Invisible<RP>>;
+
+/// Returns true for the characters forbidden on Linux and the characters, which are already
+/// in the range of the mapped characters, because [`Wsl::decoder`] would turn them into different characters.
+#[must_use]
+fn is_replaced(c: char) -> bool {
+    FORBIDDEN_SET.contains(c)
+        || (PRIVATE_USE_OFFSET..PRIVATE_USE_OFFSET + 0x80).contains(&u32::from(c))
+}
+
+/// WSL's `DrvFs` and Cygwin allow files with characters that are forbidden on Windows,
+/// by mapping those characters into the private use area (`U+F000 + c`).
+///
+/// Contrary to the other platforms this mapping is reversible with [`Wsl::decoder`],
+/// only the characters forbidden on [`Linux`](crate::platforms::Linux), invisible characters
+/// and characters in the range `U+F000..=U+F07F` are replaced with `RP`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Wsl<const RP: char> {}
+
+impl<const RP: char> Default for Wsl<RP> {
+    fn default() -> Self {
+        Self {}
+    }
+}
+
+impl<const RP: char> Wsl<RP> {
+    constant_arrays! {
+        /// Characters that are valid on Linux, but forbidden on Windows
+        const MAPPED_CHARACTERS: [char; _] = [
+            '<', '>', ':', '"', '\\', '|', '?', '*',
+            '\x01', '\x02', '\x03', '\x04', '\x05', '\x06', '\x07', '\x08',
+            '\x09', '\x0A', '\x0B', '\x0C', '\x0D', '\x0E', '\x0F', '\x10',
+            '\x11', '\x12', '\x13', '\x14', '\x15', '\x16', '\x17', '\x18',
+            '\x19', '\x1A', '\x1B', '\x1C', '\x1D', '\x1E', '\x1F',
+        ];
+    }
+
+    /// Returns a sanitizer, which restores the original name from a sanitized one.
+    #[must_use]
+    pub fn decoder(&self) -> Replacer<Sorted<[(char, char); 39]>> {
+        Replacer::from_private_use(Self::MAPPED_CHARACTERS)
+    }
+}
+
+impl<const RP: char> Platform for Wsl<RP> {
+    type FilenameSanitizer<'a> = WslSanitizer<RP>;
+    type FolderSanitizer<'a> = WslSanitizer<RP>;
+
+    fn filename_sanitizer(&self) -> Self::FilenameSanitizer<'_> {
+        Replacer::from((is_replaced as fn(char) -> bool, replacement::<RP>()))
+            .then(Replacer::to_private_use(Self::MAPPED_CHARACTERS))
+            .replace_invisible::<RP>()
+    }
+
+    fn folder_sanitizer(&self) -> Self::FolderSanitizer<'_> {
+        Replacer::from((is_replaced as fn(char) -> bool, replacement::<RP>()))
+            .then(Replacer::to_private_use(Self::MAPPED_CHARACTERS))
+            .replace_invisible::<RP>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Sanitizer;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_filename_roundtrip() {
+        let platform: Wsl<'\u{FFFD}'> = Wsl::default();
+        let string = "what?: \"a\\b\".txt";
+
+        let encoded = platform.filename_sanitizer().sanitize(string.chars());
+        let mut iter = platform.decoder().sanitize(encoded);
+
+        for c in string.chars() {
+            assert_eq!(iter.next(), Some(c));
+        }
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_filename_roundtrip_private_use() {
+        let platform: Wsl<'_'> = Wsl::default();
+        let string = "a\u{F03A}b\u{F07F}:c\u{F080}";
+
+        let encoded = platform.filename_sanitizer().sanitize(string.chars());
+        let iter = platform.decoder().sanitize(encoded);
+
+        assert_iter_eq_str!(iter, "a_b_:c\u{F080}");
+    }
+
+    #[test]
+    fn test_filename_linux_forbidden() {
+        let platform: Wsl<'_'> = Wsl::default();
+        let mut iter = platform.filename_sanitizer().sanitize("a/b*".chars());
+
+        assert_eq!(iter.next(), Some('a'));
+        assert_eq!(iter.next(), Some('_'));
+        assert_eq!(iter.next(), Some('b'));
+        assert_eq!(iter.next(), Some('\u{F02A}'));
+        assert_eq!(iter.next(), None);
+    }
+}
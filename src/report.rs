@@ -0,0 +1,666 @@
+//! Reports, which describe how a sanitizer changed a name, for example to highlight
+//! the changes in a user interface.
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::ops::Range;
+
+/// Describes, why a sanitizer changed a part of a name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Reason {
+    /// A character was replaced or removed by a [`Replacer`](crate::sanitizers::Replacer),
+    /// the platforms use it for the characters they do not allow.
+    ForbiddenCharacter,
+    /// A control character or a character of another [category](crate::sanitizers::Categories)
+    /// was replaced or removed.
+    ControlCharacter,
+    /// An invisible or bidirectional formatting character was replaced or removed.
+    InvisibleCharacter,
+    /// A whitespace character was replaced or removed.
+    Whitespace,
+    /// The name was normalized, like `e\u{301}` to `é`.
+    Normalization,
+    /// A padding character was appended to a reserved name, like `NUL` to `NUL_`.
+    ReservedName,
+    /// A trailing character, which the platform would remove, was replaced, like `a.` with `a_`,
+    /// or the empty name was replaced.
+    TrailingCharacter,
+    /// A character was removed from the start of the name.
+    StrippedPrefix,
+    /// A duplicate character or grapheme cluster was removed.
+    Duplicate,
+    /// The end of the name was removed, because the name is too long.
+    Truncated,
+    /// A character was transliterated to ASCII.
+    Transliterated,
+    /// A character or name was escaped or unescaped.
+    Escaped,
+    /// The extension of the name was changed.
+    Extension,
+    /// An emoji was replaced or removed.
+    Emoji,
+    /// A character was replaced with its confusable prototype.
+    Confusable,
+    /// The change was made by a sanitizer, which does not specify a reason, like a closure.
+    Other,
+}
+
+/// A part of the original name, which has been changed.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Edit<'a> {
+    /// The byte range in the original name, which is empty if the replacement has been inserted.
+    pub range: Range<usize>,
+    /// The byte range of the replacement in the sanitized name.
+    pub output_range: Range<usize>,
+    /// The text, which replaced the original one, an empty text means it has been removed.
+    pub replacement: &'a str,
+    pub reason: Reason,
+}
+
+/// A part of the original name and the part of the sanitized name, which it became.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Segment {
+    input: Range<usize>,
+    output: Range<usize>,
+    /// The reason of the change or `None`, if the part has been kept as it is.
+    reason: Option<Reason>,
+}
+
+/// The sanitized name together with the changes, which have been made to the original name,
+/// see [`Sanitizer::report`](crate::Sanitizer::report).
+///
+/// ```
+/// use path_sanitizer::platforms::Windows;
+/// use path_sanitizer::report::Reason;
+/// use path_sanitizer::{Platform, Sanitizer};
+///
+/// let platform: Windows<'_', '_'> = Windows::default();
+/// let report = platform.filename_sanitizer().report(" a?b");
+///
+/// assert_eq!(report.output(), "a_b");
+///
+/// let mut edits = report.edits();
+/// let edit = edits.next().unwrap();
+/// assert_eq!((edit.range, edit.replacement, edit.reason), (0..1, "", Reason::StrippedPrefix));
+/// let edit = edits.next().unwrap();
+/// assert_eq!((edit.range, edit.replacement, edit.reason), (2..3, "_", Reason::ForbiddenCharacter));
+/// assert!(edits.next().is_none());
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Report {
+    output: String,
+    /// The segments cover the input and the output without gaps and in order.
+    segments: Vec<Segment>,
+}
+
+#[cfg(feature = "alloc")]
+impl Report {
+    /// Creates the report of a single sanitizer, by comparing the characters of the `input`
+    /// and the `output`, every difference is attributed to the `reason`.
+    ///
+    /// The comparison takes `O((n + m) d)` time for `n + m` characters, which differ in `d` places,
+    /// if more than 2048 characters differ, they are reported as a single change.
+    #[must_use]
+    pub fn diff(input: &str, output: String, reason: Reason) -> Self {
+        let prefix = input
+            .chars()
+            .zip(output.chars())
+            .take_while(|(a, b)| a == b)
+            .map(|(c, _)| c.len_utf8())
+            .sum::<usize>();
+        let suffix = input[prefix..]
+            .chars()
+            .rev()
+            .zip(output[prefix..].chars().rev())
+            .take_while(|(a, b)| a == b)
+            .map(|(c, _)| c.len_utf8())
+            .sum::<usize>();
+
+        let mut segments = Vec::new();
+        push_kept(&mut segments, 0, 0, prefix);
+        diff_chars(
+            &input[prefix..input.len() - suffix],
+            &output[prefix..output.len() - suffix],
+            prefix,
+            reason,
+            &mut segments,
+        );
+        push_kept(
+            &mut segments,
+            input.len() - suffix,
+            output.len() - suffix,
+            suffix,
+        );
+
+        Self { output, segments }
+    }
+
+    /// Returns the report of applying `next` to the output of this report,
+    /// the ranges of the edits refer to the input of this report.
+    ///
+    /// If an edit of `next` overlaps an edit of this report, they are combined
+    /// and the reason of `next` is used.
+    #[must_use]
+    pub fn then(self, next: Self) -> Self {
+        let first = split_kept(&self.output, self.segments);
+        let second = split_kept(&next.output, next.segments);
+
+        let mut segments = Vec::new();
+        let (mut i, mut j) = (0, 0);
+
+        while i < first.len() || j < second.len() {
+            // removals of the first and insertions of the second report are combined with nothing
+            if let Some(segment) = first.get(i).filter(|segment| segment.output.is_empty()) {
+                segments.push(segment.clone());
+                i += 1;
+                continue;
+            }
+
+            if let Some(segment) = second.get(j).filter(|segment| segment.input.is_empty()) {
+                let position = first
+                    .get(i)
+                    .map_or_else(|| input_end(&first), |s| s.input.start);
+                segments.push(Segment {
+                    input: position..position,
+                    ..segment.clone()
+                });
+                j += 1;
+                continue;
+            }
+
+            // both segments start at the same position of the intermediate string, so they are
+            // extended until they end at the same position as well
+            let (start_i, start_j) = (i, j);
+            let mut end_first = first[i].output.end;
+            let mut end_second = second[j].input.end;
+            i += 1;
+            j += 1;
+
+            while end_first != end_second {
+                if end_first < end_second {
+                    end_first = first[i].output.end;
+                    i += 1;
+                } else {
+                    end_second = second[j].input.end;
+                    j += 1;
+                }
+            }
+
+            let reason = second[start_j..j]
+                .iter()
+                .rev()
+                .find_map(|segment| segment.reason)
+                .or_else(|| {
+                    first[start_i..i]
+                        .iter()
+                        .rev()
+                        .find_map(|segment| segment.reason)
+                });
+
+            segments.push(Segment {
+                input: first[start_i].input.start..first[i - 1].input.end,
+                output: second[start_j].output.start..second[j - 1].output.end,
+                reason,
+            });
+        }
+
+        Self {
+            output: next.output,
+            segments: merge_kept(segments),
+        }
+    }
+
+    /// Returns the sanitized name.
+    #[must_use]
+    pub fn output(&self) -> &str {
+        &self.output
+    }
+
+    #[must_use]
+    pub fn into_output(self) -> String {
+        self.output
+    }
+
+    /// Returns the changes in the order of the original name.
+    pub fn edits(&self) -> impl Iterator<Item = Edit<'_>> + '_ {
+        self.segments.iter().filter_map(|segment| {
+            Some(Edit {
+                range: segment.input.clone(),
+                output_range: segment.output.clone(),
+                replacement: &self.output[segment.output.clone()],
+                reason: segment.reason?,
+            })
+        })
+    }
+
+    /// Returns the byte range in the original name, which became the byte at `index`
+    /// of the sanitized name.
+    #[must_use]
+    pub fn source_of(&self, index: usize) -> Option<Range<usize>> {
+        let segment = self
+            .segments
+            .iter()
+            .find(|segment| segment.output.contains(&index))?;
+
+        if segment.reason.is_some() {
+            Some(segment.input.clone())
+        } else {
+            let position = segment.input.start + index - segment.output.start;
+            Some(position..position + 1)
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn input_end(segments: &[Segment]) -> usize {
+    segments.last().map_or(0, |segment| segment.input.end)
+}
+
+#[cfg(feature = "alloc")]
+fn push_kept(segments: &mut Vec<Segment>, input: usize, output: usize, len: usize) {
+    if len > 0 {
+        segments.push(Segment {
+            input: input..input + len,
+            output: output..output + len,
+            reason: None,
+        });
+    }
+}
+
+/// The maximum number of characters of the input and the output, which are compared by [`diff_chars`],
+/// longer parts are reported as a single change, so a long name can not make the report slow.
+#[cfg(feature = "alloc")]
+const MAX_DIFF_LENGTH: usize = 2048;
+
+/// Appends the segments of the difference between `input` and `output`,
+/// which start at `offset` in the original strings, based on their longest common subsequence.
+///
+/// A change of as many characters as it is replaced with is split into single characters,
+/// like the replacements of most sanitizers.
+#[cfg(feature = "alloc")]
+fn diff_chars(
+    input: &str,
+    output: &str,
+    offset: usize,
+    reason: Reason,
+    segments: &mut Vec<Segment>,
+) {
+    let a = input.char_indices().collect::<Vec<_>>();
+    let b = output.char_indices().collect::<Vec<_>>();
+
+    let mut common = Vec::new();
+    if a.len() + b.len() <= MAX_DIFF_LENGTH {
+        let chars_a = a.iter().map(|(_, c)| *c).collect::<Vec<_>>();
+        let chars_b = b.iter().map(|(_, c)| *c).collect::<Vec<_>>();
+        common_chars(&chars_a, &chars_b, (0, 0), &mut common);
+    }
+
+    // the byte position of the character at `index` in the original string
+    let at_a = |index: usize| a.get(index).map_or(input.len(), |(position, _)| *position) + offset;
+    let at_b = |index: usize| b.get(index).map_or(output.len(), |(position, _)| *position) + offset;
+    let mut push = |start: (usize, usize), end: (usize, usize), reason: Option<Reason>| {
+        segments.push(Segment {
+            input: at_a(start.0)..at_a(end.0),
+            output: at_b(start.1)..at_b(end.1),
+            reason,
+        });
+    };
+
+    let (mut start_i, mut start_j) = (0, 0);
+
+    for (i, j) in common.into_iter().chain([(a.len(), b.len())]) {
+        // the characters since the last common character have been changed
+        let (removed, inserted) = (i - start_i, j - start_j);
+        if removed == inserted {
+            for k in 0..removed {
+                let start = (start_i + k, start_j + k);
+                push(start, (start.0 + 1, start.1 + 1), Some(reason));
+            }
+        } else {
+            push((start_i, start_j), (i, j), Some(reason));
+        }
+
+        if (i, j) == (a.len(), b.len()) {
+            break;
+        }
+
+        push((i, j), (i + 1, j + 1), None);
+        (start_i, start_j) = (i + 1, j + 1);
+    }
+}
+
+/// Appends the indices of the characters of a longest common subsequence of `a` and `b` to `common`,
+/// which are offset by `start`.
+///
+/// This is the linear space variant of the algorithm by Eugene W. Myers, "An O(ND) Difference Algorithm
+/// and Its Variations", which splits the strings at the middle of the shortest edit script.
+#[cfg(feature = "alloc")]
+fn common_chars(a: &[char], b: &[char], start: (usize, usize), common: &mut Vec<(usize, usize)>) {
+    let prefix = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+    let suffix = a[prefix..]
+        .iter()
+        .rev()
+        .zip(b[prefix..].iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+
+    common.extend((0..prefix).map(|k| (start.0 + k, start.1 + k)));
+
+    let a_middle = &a[prefix..a.len() - suffix];
+    let b_middle = &b[prefix..b.len() - suffix];
+    let start_middle = (start.0 + prefix, start.1 + prefix);
+
+    // without a common prefix and suffix the edit script has at least two edits,
+    // so both halves are shorter than the strings
+    if !a_middle.is_empty() && !b_middle.is_empty() {
+        let (snake_start, snake_end) = middle_snake(a_middle, b_middle);
+
+        common_chars(
+            &a_middle[..snake_start.0],
+            &b_middle[..snake_start.1],
+            start_middle,
+            common,
+        );
+        common.extend((0..snake_end.0 - snake_start.0).map(|k| {
+            (
+                start_middle.0 + snake_start.0 + k,
+                start_middle.1 + snake_start.1 + k,
+            )
+        }));
+        common_chars(
+            &a_middle[snake_end.0..],
+            &b_middle[snake_end.1..],
+            (start_middle.0 + snake_end.0, start_middle.1 + snake_end.1),
+            common,
+        );
+    }
+
+    common.extend((0..suffix).map(|k| {
+        (
+            start_middle.0 + a_middle.len() + k,
+            start_middle.1 + b_middle.len() + k,
+        )
+    }));
+}
+
+/// Returns the start and the end of the diagonal in the middle of a shortest edit script
+/// of `a` and `b`, which are both not empty.
+///
+/// The names of the variables are the ones of the paper.
+#[cfg(feature = "alloc")]
+#[allow(
+    clippy::cast_possible_wrap,
+    clippy::cast_sign_loss,
+    clippy::many_single_char_names
+)]
+fn middle_snake(a: &[char], b: &[char]) -> ((usize, usize), (usize, usize)) {
+    let (n, m) = (a.len() as isize, b.len() as isize);
+    let delta = n - m;
+    let max = (n + m + 1) / 2;
+
+    // forward[k] and backward[k] are the furthest x on the diagonal k = x - y,
+    // the backward x and diagonal count from the end of the strings
+    let mut forward = alloc::vec![0_isize; 2 * max as usize + 3];
+    let mut backward = forward.clone();
+    let index = |k: isize| (k + max + 1) as usize;
+
+    for d in 0..=max {
+        for k in (-d..=d).step_by(2) {
+            let mut x = if k == -d || (k != d && forward[index(k - 1)] < forward[index(k + 1)]) {
+                forward[index(k + 1)]
+            } else {
+                forward[index(k - 1)] + 1
+            };
+            let start = (x, x - k);
+            while x < n && x - k < m && a[x as usize] == b[(x - k) as usize] {
+                x += 1;
+            }
+            forward[index(k)] = x;
+
+            let c = delta - k;
+            if delta % 2 != 0 && (1 - d..d).contains(&c) && x + backward[index(c)] >= n {
+                return (
+                    (start.0 as usize, start.1 as usize),
+                    (x as usize, (x - k) as usize),
+                );
+            }
+        }
+
+        for c in (-d..=d).step_by(2) {
+            let mut x = if c == -d || (c != d && backward[index(c - 1)] < backward[index(c + 1)]) {
+                backward[index(c + 1)]
+            } else {
+                backward[index(c - 1)] + 1
+            };
+            let end = (n - x, m - (x - c));
+            while x < n && x - c < m && a[(n - 1 - x) as usize] == b[(m - 1 - (x - c)) as usize] {
+                x += 1;
+            }
+            backward[index(c)] = x;
+
+            let k = delta - c;
+            if delta % 2 == 0 && (-d..=d).contains(&k) && x + forward[index(k)] >= n {
+                return (
+                    ((n - x) as usize, (m - (x - c)) as usize),
+                    (end.0 as usize, end.1 as usize),
+                );
+            }
+        }
+    }
+
+    unreachable!("the shortest edit script has at most n + m edits")
+}
+
+/// Splits the kept segments into segments of single characters, which can be combined
+/// with the segments of another report.
+#[cfg(feature = "alloc")]
+fn split_kept(output: &str, segments: Vec<Segment>) -> Vec<Segment> {
+    let mut result = Vec::with_capacity(segments.len());
+
+    for segment in segments {
+        if segment.reason.is_some() {
+            result.push(segment);
+            continue;
+        }
+
+        for (index, c) in output[segment.output.clone()].char_indices() {
+            push_kept(
+                &mut result,
+                segment.input.start + index,
+                segment.output.start + index,
+                c.len_utf8(),
+            );
+        }
+    }
+
+    result
+}
+
+/// Combines adjacent kept segments.
+#[cfg(feature = "alloc")]
+fn merge_kept(segments: Vec<Segment>) -> Vec<Segment> {
+    let mut result: Vec<Segment> = Vec::with_capacity(segments.len());
+
+    for segment in segments {
+        match result.last_mut() {
+            Some(last) if last.reason.is_none() && segment.reason.is_none() => {
+                last.input.end = segment.input.end;
+                last.output.end = segment.output.end;
+            }
+            _ => result.push(segment),
+        }
+    }
+
+    result
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use crate::platforms::Windows;
+    use crate::sanitizers::{Control, Padder, Whitespace, REMOVE};
+    use crate::{Platform, Sanitizer, SanitizerExt};
+
+    use alloc::string::ToString;
+    use pretty_assertions::assert_eq;
+    use proptest::prelude::*;
+
+    fn edits(report: &Report) -> Vec<(Range<usize>, &str, Reason)> {
+        report
+            .edits()
+            .map(|edit| (edit.range, edit.replacement, edit.reason))
+            .collect()
+    }
+
+    #[test]
+    fn test_diff() {
+        let report = Report::diff("a?b??c", "a_b_c".to_string(), Reason::Other);
+
+        assert_eq!(report.output(), "a_b_c");
+        assert_eq!(
+            edits(&report),
+            [(1..2, "_", Reason::Other), (3..5, "_", Reason::Other)]
+        );
+    }
+
+    #[test]
+    fn test_single() {
+        let sanitizer: Control<REMOVE> = Control::default();
+        let report = sanitizer.report("\0a\nb");
+
+        assert_eq!(report.output(), "ab");
+        assert_eq!(
+            edits(&report),
+            [
+                (0..1, "", Reason::ControlCharacter),
+                (2..3, "", Reason::ControlCharacter)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_then() {
+        let sanitizer = Whitespace::<'_'>::default()
+            .then(Control::<'?'>::default())
+            .deduplicate(|c| c == '_');
+        let report = sanitizer.report("a \t\0b");
+
+        assert_eq!(report.output(), "a_?b");
+        assert_eq!(
+            edits(&report),
+            [
+                (1..2, "_", Reason::Whitespace),
+                (2..3, "", Reason::Duplicate),
+                (3..4, "?", Reason::ControlCharacter)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_overlapping() {
+        let sanitizer = Whitespace::<'x'>::default()
+            .then(Padder::<_, '_', 1>::new(["ax"].map(str::chars), None));
+        let report = sanitizer.clone().report("a b");
+
+        assert_eq!(report.output(), "axb");
+        assert_eq!(edits(&report), [(1..2, "x", Reason::Whitespace)]);
+
+        // the padding is inserted after the replaced character
+        let report = sanitizer.report("a ");
+        assert_eq!(report.output(), "ax_");
+        assert_eq!(
+            edits(&report),
+            [
+                (1..2, "x", Reason::Whitespace),
+                (2..2, "_", Reason::ReservedName)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_platform() {
+        let platform: Windows<'_', '_'> = Windows::default();
+        let report = platform.filename_sanitizer().report("  NUL");
+
+        assert_eq!(report.output(), "NUL_");
+        assert_eq!(
+            edits(&report),
+            [
+                (0..2, "", Reason::StrippedPrefix),
+                (5..5, "_", Reason::ReservedName)
+            ]
+        );
+        assert_eq!(report.source_of(1), Some(3..4));
+        assert_eq!(report.source_of(3), Some(5..5));
+    }
+
+    #[test]
+    fn test_platform_reasons() {
+        let platform: Windows<'_', '_'> = Windows::default();
+        let report = platform.filename_sanitizer().report("a\u{202E}b\nc");
+
+        assert_eq!(report.output(), "a_b_c");
+        assert_eq!(
+            edits(&report),
+            [
+                (1..4, "_", Reason::InvisibleCharacter),
+                (5..6, "_", Reason::ControlCharacter)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_long() {
+        let input = "x".to_string() + &"ab".repeat(1500);
+        let output = "yz".to_string() + &"ba".repeat(1500);
+        let report = Report::diff(&input, output.clone(), Reason::Other);
+
+        assert_eq!(report.output(), output);
+        assert_eq!(edits(&report), [(0..3001, output.as_str(), Reason::Other)]);
+    }
+
+    /// Returns the length of the longest common subsequence of `a` and `b`.
+    fn common_len(a: &str, b: &str) -> usize {
+        let b = b.chars().collect::<Vec<_>>();
+        let mut lengths = alloc::vec![0; b.len() + 1];
+
+        for c in a.chars() {
+            let mut diagonal = 0;
+            for (j, d) in b.iter().enumerate() {
+                let length = if c == *d {
+                    diagonal + 1
+                } else {
+                    lengths[j].max(lengths[j + 1])
+                };
+                diagonal = lengths[j + 1];
+                lengths[j + 1] = length;
+            }
+        }
+
+        lengths[b.len()]
+    }
+
+    proptest! {
+        #[test]
+        fn test_diff_common(input in "[ab?]{0,24}", output in "[ab_]{0,24}") {
+            let report = Report::diff(&input, output.clone(), Reason::Other);
+            let kept = report
+                .segments
+                .iter()
+                .filter(|segment| segment.reason.is_none())
+                .map(|segment| segment.input.len())
+                .sum::<usize>();
+
+            prop_assert_eq!(kept, common_len(&input, &output));
+            prop_assert_eq!(report.segments.first().map_or(0, |s| s.input.start), 0);
+            prop_assert_eq!(input_end(&report.segments), input.len());
+        }
+    }
+}
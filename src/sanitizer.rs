@@ -1,10 +1,44 @@
 use core::iter;
 
+use crate::report::Reason;
+#[cfg(feature = "alloc")]
+use crate::report::Report;
+
 pub trait Sanitizer {
     type Iter<I: Iterator<Item = char>>: Iterator<Item = char>;
 
     #[must_use]
     fn sanitize<I: Iterator<Item = char>>(self, iter: I) -> Self::Iter<I>;
+
+    /// Sanitizes the characters with a copy of the sanitizer, so one configured sanitizer
+    /// can be applied to many names.
+    ///
+    /// A closure with state, which should be shared between the names, can be passed
+    /// as `&mut closure` instead.
+    #[must_use]
+    fn sanitize_ref<I: Iterator<Item = char>>(&self, iter: I) -> Self::Iter<I>
+    where
+        Self: Clone,
+    {
+        self.clone().sanitize(iter)
+    }
+
+    /// Describes, why this sanitizer changes a name, see [`Reason`].
+    #[must_use]
+    fn reason(&self) -> Reason {
+        Reason::Other
+    }
+
+    /// Sanitizes the `input` and reports the changes together with their [`reason`](Self::reason).
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    fn report(self, input: &str) -> Report
+    where
+        Self: Sized,
+    {
+        let reason = self.reason();
+        Report::diff(input, self.sanitize(input.chars()).collect(), reason)
+    }
 }
 
 impl Sanitizer for () {
@@ -27,6 +61,7 @@ where
     }
 }
 
+#[derive(Debug, Clone, Copy)]
 pub struct BoolClosureAdapter<F>(F);
 
 impl<F> From<F> for BoolClosureAdapter<F>
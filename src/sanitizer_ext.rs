@@ -1,9 +1,19 @@
 use core::str::Chars;
 
 use crate::sanitizer::Sanitizer;
+#[cfg(feature = "confusables")]
+use crate::sanitizers::Skeleton;
 use crate::sanitizers::{
-    Control, Deduplicator, Padder, PrefixStripper, Replacer, Then, Whitespace,
+    Categories, Control, Deduplicator, ExtensionAction, ExtensionPolicy, GeneralCategory,
+    InfixPadder, Invisible, Padder, PrefixStripper, Replacer, RuntimePadder, Then,
+    TrailingReplacer, Transliterate, Truncate, Whitespace,
 };
+#[cfg(feature = "emoji")]
+use crate::sanitizers::{EmojiAction, EmojiPolicy};
+#[cfg(feature = "normalization")]
+use crate::sanitizers::{Form, Normalize};
+#[cfg(feature = "graphemes")]
+use crate::sanitizers::{GraphemeDeduplicator, GraphemeTruncate};
 use crate::utils::Map;
 
 pub trait SanitizerExt: Sanitizer + Sized {
@@ -17,11 +27,35 @@ pub trait SanitizerExt: Sanitizer + Sized {
         self.then(Deduplicator::new(f))
     }
 
+    /// Removes duplicate grapheme clusters, see [`GraphemeDeduplicator`].
+    #[cfg(feature = "graphemes")]
+    #[must_use]
+    fn deduplicate_graphemes<F: FnMut(&str) -> bool>(
+        self,
+        f: F,
+    ) -> Then<Self, GraphemeDeduplicator<F>> {
+        self.then(GraphemeDeduplicator::new(f))
+    }
+
     #[must_use]
     fn replace_control<const RP: char>(self) -> Then<Self, Control<RP>> {
         self.then(Control::default())
     }
 
+    /// Replaces all characters of the `categories`, see [`GeneralCategory`].
+    #[must_use]
+    fn replace_categories<const RP: char>(
+        self,
+        categories: Categories,
+    ) -> Then<Self, GeneralCategory<RP>> {
+        self.then(GeneralCategory::new(categories))
+    }
+
+    #[must_use]
+    fn replace_invisible<const RP: char>(self) -> Then<Self, Invisible<RP>> {
+        self.then(Invisible::default())
+    }
+
     #[must_use]
     fn replace_whitespace<const RP: char>(self) -> Then<Self, Whitespace<RP>> {
         self.then(Whitespace::default())
@@ -32,14 +66,110 @@ pub trait SanitizerExt: Sanitizer + Sized {
         self.then(Replacer::from(map))
     }
 
+    /// Replaces characters with strings, an empty string removes the character.
+    #[must_use]
+    fn replace_str<'a, M: Map<char, &'a str>>(self, map: M) -> Then<Self, Replacer<M, &'a str>> {
+        self.then(Replacer::from(map))
+    }
+
+    #[cfg(feature = "normalization")]
+    #[must_use]
+    fn normalize(self, form: Form) -> Then<Self, Normalize> {
+        self.then(Normalize::new(form))
+    }
+
+    /// Maps every character to its confusable prototype, see [`Skeleton`].
+    #[cfg(feature = "confusables")]
+    #[must_use]
+    fn skeleton(self) -> Then<Self, Skeleton> {
+        self.then(Skeleton::default())
+    }
+
+    /// Transliterates non-ASCII characters to ASCII, see [`Transliterate`].
+    #[must_use]
+    fn transliterate(self, fallback: &str) -> Then<Self, Transliterate<'_>> {
+        self.then(Transliterate::new(fallback))
+    }
+
+    /// Applies the `action` to names with an extension from the `deny` list, see [`ExtensionPolicy`].
+    #[must_use]
+    fn extension_policy<'a, const N: usize>(
+        self,
+        deny: [&'a str; N],
+        action: ExtensionAction<'a>,
+    ) -> Then<Self, ExtensionPolicy<'a, N>> {
+        self.then(ExtensionPolicy::new(deny, action))
+    }
+
+    /// Truncates the name to at most `max_len` UTF-8 bytes, see [`Truncate`].
+    #[must_use]
+    fn truncate(self, max_len: usize) -> Then<Self, Truncate> {
+        self.then(Truncate::new(max_len))
+    }
+
+    /// Truncates the name to at most `max_len` UTF-16 code units, see [`Truncate`].
+    #[must_use]
+    fn truncate_utf16(self, max_len: usize) -> Then<Self, Truncate> {
+        self.then(Truncate::utf16(max_len))
+    }
+
+    /// Truncates the name to at most `max_len` UTF-8 bytes without splitting grapheme clusters,
+    /// see [`GraphemeTruncate`].
+    #[cfg(feature = "graphemes")]
+    #[must_use]
+    fn truncate_graphemes(self, max_len: usize) -> Then<Self, GraphemeTruncate> {
+        self.then(GraphemeTruncate::new(max_len))
+    }
+
+    /// Applies the `action` to every emoji, see [`EmojiPolicy`].
+    #[cfg(feature = "emoji")]
+    #[must_use]
+    fn emoji_policy(self, action: EmojiAction<'_>) -> Then<Self, EmojiPolicy<'_>> {
+        self.then(EmojiPolicy::new(action))
+    }
+
+    /// Appends the padding character `P` to names, which match one of the `strings`, see [`Padder`].
     #[must_use]
-    fn padding<'a, const P: char, const N: usize>(
+    fn padding<const P: char, const N: usize>(
         self,
-        strings: [&'a str; N],
-    ) -> Then<Self, Padder<Chars<'a>, P, N>> {
+        strings: [&str; N],
+    ) -> Then<Self, Padder<Chars<'_>, P, N>> {
         self.then(Padder::new(strings.map(str::chars), None))
     }
 
+    /// Appends the `padding` character chosen at runtime to names, which match one of the `strings`,
+    /// see [`RuntimePadder`].
+    #[must_use]
+    fn runtime_padding<const N: usize>(
+        self,
+        padding: char,
+        strings: [&str; N],
+    ) -> Then<Self, RuntimePadder<Chars<'_>, N>> {
+        self.then(RuntimePadder::new(strings.map(str::chars), padding, None))
+    }
+
+    /// Inserts the `padding` between `prefix` and `suffix`, wherever both appear together,
+    /// see [`InfixPadder`].
+    #[must_use]
+    fn pad_infix<'a>(
+        self,
+        prefix: &'a str,
+        suffix: &'a str,
+        padding: char,
+    ) -> Then<Self, InfixPadder<'a>> {
+        self.then(InfixPadder::new(prefix, suffix, padding))
+    }
+
+    /// Replaces the last character, if `is_trailing` returns true for it, see [`TrailingReplacer`].
+    #[must_use]
+    fn replace_trailing<F: FnMut(char) -> bool>(
+        self,
+        is_trailing: F,
+        replacement: char,
+    ) -> Then<Self, TrailingReplacer<F>> {
+        self.then(TrailingReplacer::new(is_trailing, replacement))
+    }
+
     #[must_use]
     fn strip_prefix<P: FnMut(char) -> bool>(self, is_prefix: P) -> Then<Self, PrefixStripper<P>> {
         self.then(PrefixStripper::new(is_prefix))
@@ -0,0 +1,285 @@
+use core::iter::FusedIterator;
+use core::ops::BitOr;
+
+#[cfg(feature = "general-category")]
+use unicode_general_category::{get_general_category, GeneralCategory as Gc};
+
+use crate::encoding::SURROGATE_OFFSET;
+use crate::report::Reason;
+use crate::sanitizer::Sanitizer;
+use crate::sanitizers::{replacement, replacement_for, OptionReplacerIter, Replacer};
+use crate::utils::Map;
+
+/// A set of (pseudo) general categories of characters, which can be combined with `|`,
+/// like `Categories::CONTROL | Categories::NONCHARACTER`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Categories(u8);
+
+impl Categories {
+    /// The empty set.
+    pub const NONE: Self = Self(0);
+    /// Control characters (`Cc`), like `\0`, `\n` or the C1 controls `U+0080..=U+009F`.
+    pub const CONTROL: Self = Self(1 << 0);
+    /// Format characters (`Cf`), like the soft hyphen or the zero width joiner.
+    #[cfg(feature = "general-category")]
+    pub const FORMAT: Self = Self(1 << 1);
+    /// Private use characters (`Co`), like `U+E000` or the supplementary private use areas.
+    pub const PRIVATE_USE: Self = Self(1 << 2);
+    /// Code points, which are not assigned to a character (`Cn`), this includes the noncharacters.
+    #[cfg(feature = "general-category")]
+    pub const UNASSIGNED: Self = Self(1 << 3);
+    /// Surrogates (`Cs`) can not be stored in a `char`, so this matches the unpaired surrogates,
+    /// which [`DecodeMode::PassThrough`](crate::encoding::DecodeMode::PassThrough) maps to
+    /// `U+100000 + s`.
+    pub const SURROGATE: Self = Self(1 << 4);
+    /// The 66 noncharacters `U+FDD0..=U+FDEF` and the last two code points of every plane,
+    /// like `U+FFFE` and `U+FFFF`.
+    pub const NONCHARACTER: Self = Self(1 << 5);
+
+    /// The categories, which are replaced by the platforms.
+    pub const DEFAULT: Self = Self::CONTROL.union(Self::NONCHARACTER);
+
+    /// Returns the set, which contains the categories of both sets.
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    #[must_use]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns true if the character is in one of the categories.
+    #[must_use]
+    pub fn matches(self, c: char) -> bool {
+        (self.contains(Self::CONTROL) && c.is_control())
+            || (self.contains(Self::PRIVATE_USE) && is_private_use(c))
+            || (self.contains(Self::SURROGATE) && is_mapped_surrogate(c))
+            || (self.contains(Self::NONCHARACTER) && is_noncharacter(c))
+            || self.matches_general_category(c)
+    }
+
+    #[cfg(feature = "general-category")]
+    #[must_use]
+    fn matches_general_category(self, c: char) -> bool {
+        if !self.contains(Self::FORMAT) && !self.contains(Self::UNASSIGNED) {
+            return false;
+        }
+
+        match get_general_category(c) {
+            Gc::Format => self.contains(Self::FORMAT),
+            Gc::Unassigned => self.contains(Self::UNASSIGNED),
+            _ => false,
+        }
+    }
+
+    #[cfg(not(feature = "general-category"))]
+    #[allow(clippy::unused_self)]
+    #[must_use]
+    const fn matches_general_category(self, _: char) -> bool {
+        false
+    }
+}
+
+impl<V> Map<char, V> for (Categories, V) {
+    fn get(&self, key: &char) -> Option<&V> {
+        let (categories, v) = self;
+        categories.matches(*key).then_some(v)
+    }
+}
+
+impl BitOr for Categories {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self::Output {
+        self.union(other)
+    }
+}
+
+#[must_use]
+const fn is_private_use(c: char) -> bool {
+    matches!(c, '\u{E000}'..='\u{F8FF}' | '\u{F0000}'..='\u{FFFFD}' | '\u{100000}'..='\u{10FFFD}')
+}
+
+#[must_use]
+const fn is_mapped_surrogate(c: char) -> bool {
+    matches!(
+        (c as u32).checked_sub(SURROGATE_OFFSET),
+        Some(0xD800..=0xDFFF)
+    )
+}
+
+#[must_use]
+const fn is_noncharacter(c: char) -> bool {
+    matches!(c, '\u{FDD0}'..='\u{FDEF}') || (c as u32) & 0xFFFE == 0xFFFE
+}
+
+/// This sanitizer replaces all characters of the [`Categories`] with the specified replacement character.
+///
+/// If the replacement character is [`REMOVE`](crate::sanitizers::REMOVE), the characters are removed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GeneralCategory<const RP: char> {
+    categories: Categories,
+}
+
+impl<const RP: char> GeneralCategory<RP> {
+    #[must_use]
+    pub const fn new(categories: Categories) -> Self {
+        Self { categories }
+    }
+}
+
+impl<const RP: char> Default for GeneralCategory<RP> {
+    fn default() -> Self {
+        Self::new(Categories::DEFAULT)
+    }
+}
+
+impl<const RP: char> Sanitizer for GeneralCategory<RP> {
+    type Iter<I: Iterator<Item = char>> = GeneralCategoryIter<I, RP>;
+
+    fn sanitize<I: Iterator<Item = char>>(self, iter: I) -> Self::Iter<I> {
+        GeneralCategoryIter {
+            iter,
+            categories: self.categories,
+        }
+    }
+
+    fn reason(&self) -> Reason {
+        Reason::ControlCharacter
+    }
+}
+
+/// The same as [`GeneralCategory`], but the replacement character is chosen at runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RuntimeGeneralCategory {
+    categories: Categories,
+    replacement: Option<char>,
+}
+
+impl RuntimeGeneralCategory {
+    /// If the `replacement` is [`REMOVE`](crate::sanitizers::REMOVE), the characters are removed.
+    #[must_use]
+    pub const fn new(categories: Categories, replacement: char) -> Self {
+        Self {
+            categories,
+            replacement: replacement_for(replacement),
+        }
+    }
+}
+
+impl Sanitizer for RuntimeGeneralCategory {
+    type Iter<I: Iterator<Item = char>> = OptionReplacerIter<I, (Categories, Option<char>)>;
+
+    fn sanitize<I: Iterator<Item = char>>(self, iter: I) -> Self::Iter<I> {
+        Replacer::from((self.categories, self.replacement)).sanitize(iter)
+    }
+
+    fn reason(&self) -> Reason {
+        Reason::ControlCharacter
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GeneralCategoryIter<I: Iterator<Item = char>, const RP: char> {
+    iter: I,
+    categories: Categories,
+}
+
+impl<I: Iterator<Item = char>, const RP: char> Iterator for GeneralCategoryIter<I, RP> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for c in self.iter.by_ref() {
+            if !self.categories.matches(c) {
+                return Some(c);
+            } else if let Some(c) = replacement::<RP>() {
+                return Some(c);
+            }
+        }
+
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower_bound, upper_bound) = self.iter.size_hint();
+
+        if replacement::<RP>().is_some() {
+            (lower_bound, upper_bound)
+        } else {
+            (0, upper_bound)
+        }
+    }
+}
+
+impl<I, const RP: char> DoubleEndedIterator for GeneralCategoryIter<I, RP>
+where
+    I: DoubleEndedIterator<Item = char>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while let Some(c) = self.iter.next_back() {
+            if !self.categories.matches(c) {
+                return Some(c);
+            } else if let Some(c) = replacement::<RP>() {
+                return Some(c);
+            }
+        }
+
+        None
+    }
+}
+
+impl<I: FusedIterator<Item = char>, const RP: char> FusedIterator for GeneralCategoryIter<I, RP> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sanitizers::REMOVE;
+
+    #[test]
+    fn test_default() {
+        let sanitizer: GeneralCategory<'_'> = GeneralCategory::default();
+        let iter = sanitizer.sanitize("a\nb\u{85}c\u{FDD0}d\u{FFFE}e\u{10FFFF}\u{E000}".chars());
+
+        assert_iter_eq_str!(iter, "a_b_c_d_e_\u{E000}");
+    }
+
+    #[test]
+    fn test_remove() {
+        let sanitizer: GeneralCategory<REMOVE> =
+            GeneralCategory::new(Categories::PRIVATE_USE | Categories::SURROGATE);
+        let iter = sanitizer.sanitize("\u{E000}a\u{10D800}b\u{F0000}\n".chars());
+
+        assert_iter_eq_str!(iter, "ab\n");
+    }
+
+    #[test]
+    fn test_surrogate() {
+        let sanitizer: GeneralCategory<'_'> = GeneralCategory::new(Categories::SURROGATE);
+        let iter =
+            sanitizer.sanitize("a\u{10D800}b\u{10DFFF}c\u{FFFD}d\u{10D7FF}\u{10E000}".chars());
+
+        assert_iter_eq_str!(iter, "a_b_c\u{FFFD}d\u{10D7FF}\u{10E000}");
+    }
+
+    #[cfg(feature = "general-category")]
+    #[test]
+    fn test_format_unassigned() {
+        let sanitizer: GeneralCategory<'_'> =
+            GeneralCategory::new(Categories::FORMAT | Categories::UNASSIGNED);
+        let iter = sanitizer.sanitize("a\u{200D}b\u{00AD}c\u{0378}d\u{FFFF}".chars());
+
+        assert_iter_eq_str!(iter, "a_b_c_d_");
+    }
+
+    #[test]
+    fn test_runtime() {
+        let sanitizer = RuntimeGeneralCategory::new(Categories::DEFAULT, REMOVE);
+        assert_iter_eq_str!(sanitizer.sanitize("a\0b\u{FFFE}c".chars()), "abc");
+
+        let sanitizer = RuntimeGeneralCategory::new(Categories::CONTROL, '_');
+        assert_iter_eq_str!(sanitizer.sanitize("a\nb".chars()), "a_b");
+    }
+}
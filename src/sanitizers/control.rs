@@ -1,8 +1,13 @@
 use core::iter;
 
+use crate::report::Reason;
 use crate::sanitizer::Sanitizer;
+use crate::sanitizers::replacement;
 
 /// This sanitizer replaces control characters like `\0` or `\a` with the specified replacement character.
+///
+/// If the replacement character is [`REMOVE`](crate::sanitizers::REMOVE), the control characters are removed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Control<const RP: char> {}
 
 impl<const RP: char> Default for Control<RP> {
@@ -12,9 +17,19 @@ impl<const RP: char> Default for Control<RP> {
 }
 
 impl<const RP: char> Sanitizer for Control<RP> {
-    type Iter<I: Iterator<Item = char>> = iter::Map<I, fn(char) -> char>;
+    type Iter<I: Iterator<Item = char>> = iter::FilterMap<I, fn(char) -> Option<char>>;
 
     fn sanitize<I: Iterator<Item = char>>(self, iter: I) -> Self::Iter<I> {
-        iter.map(|c| if c.is_control() { RP } else { c })
+        iter.filter_map(|c| {
+            if c.is_control() {
+                replacement::<RP>()
+            } else {
+                Some(c)
+            }
+        })
+    }
+
+    fn reason(&self) -> Reason {
+        Reason::ControlCharacter
     }
 }
@@ -1,12 +1,16 @@
+use core::iter::FusedIterator;
+
+use crate::report::Reason;
 use crate::sanitizer::Sanitizer;
 
 /// Removes duplicate characters, by only keeping the first character
 /// in a chain of multiple characters where the closure returns true.
+#[derive(Debug, Clone, Copy)]
 pub struct Deduplicator<F>(F);
 
 impl<F: FnMut(char) -> bool> Deduplicator<F> {
     #[must_use]
-    pub(crate) fn new(f: F) -> Self {
+    pub(crate) const fn new(f: F) -> Self {
         Self(f)
     }
 }
@@ -17,8 +21,13 @@ impl<F: FnMut(char) -> bool> Sanitizer for Deduplicator<F> {
     fn sanitize<I: Iterator<Item = char>>(self, iter: I) -> Self::Iter<I> {
         DeduplicatorIter::new(self.0, iter)
     }
+
+    fn reason(&self) -> Reason {
+        Reason::Duplicate
+    }
 }
 
+#[derive(Debug, Clone)]
 pub struct DeduplicatorIter<F: FnMut(char) -> bool, I: Iterator<Item = char>> {
     f: F,
     iter: I,
@@ -27,7 +36,7 @@ pub struct DeduplicatorIter<F: FnMut(char) -> bool, I: Iterator<Item = char>> {
 
 impl<F: FnMut(char) -> bool, I: Iterator<Item = char>> DeduplicatorIter<F, I> {
     #[must_use]
-    fn new(f: F, iter: I) -> Self {
+    const fn new(f: F, iter: I) -> Self {
         Self {
             f,
             iter,
@@ -54,6 +63,23 @@ impl<F: FnMut(char) -> bool, I: Iterator<Item = char>> Iterator for Deduplicator
 
         None
     }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower_bound, upper_bound) = self.iter.size_hint();
+
+        // the first of the remaining characters is kept, unless it continues a chain of duplicates
+        let lower_bound = if self.flag { 0 } else { lower_bound.min(1) };
+
+        (lower_bound, upper_bound)
+    }
+}
+
+impl<F, I> FusedIterator for DeduplicatorIter<F, I>
+where
+    F: FnMut(char) -> bool,
+    I: FusedIterator<Item = char>,
+{
 }
 
 #[cfg(test)]
@@ -77,4 +103,13 @@ mod tests {
         assert_eq!(iter.next(), Some(' '));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn test_size_hint() {
+        let mut iter = Deduplicator::new(|c| c == '_').sanitize(['_', '_', 'a'].into_iter());
+        assert_eq!(iter.size_hint(), (1, Some(3)));
+
+        assert_eq!(iter.next(), Some('_'));
+        assert_eq!(iter.size_hint(), (0, Some(2)));
+    }
 }
@@ -0,0 +1,214 @@
+use core::iter::FusedIterator;
+use core::str::Chars;
+
+use emojis::Emoji;
+
+use crate::report::Reason;
+use crate::sanitizer::Sanitizer;
+use crate::utils::{Cluster, Segmenter};
+
+/// What an [`EmojiPolicy`] should do with an emoji.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum EmojiAction<'a> {
+    /// Keeps the emoji as it is.
+    Keep,
+    /// Removes the emoji.
+    Remove,
+    /// Replaces the emoji with the string, for example `a😄` becomes `a_` with `Replace("_")`.
+    Replace(&'a str),
+    /// Replaces the emoji with its CLDR short name between colons,
+    /// like `:grinning face with smiling eyes:` for `😄`.
+    ShortName,
+}
+
+/// Applies the [`EmojiAction`] to every emoji in the name.
+///
+/// Emoji are detected as whole grapheme clusters, so ZWJ sequences like `👨‍👩‍👧`, flags and emoji with skin tones
+/// or variation selectors are handled as a single emoji.
+///
+/// Characters without the `Emoji_Presentation` property like `©` or `☺`, which are displayed as text by default,
+/// are only treated as emoji, if they are followed by the emoji variation selector `U+FE0F`,
+/// while characters like `⭐` or `✅` are emoji on their own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EmojiPolicy<'a> {
+    action: EmojiAction<'a>,
+}
+
+impl<'a> EmojiPolicy<'a> {
+    #[must_use]
+    pub const fn new(action: EmojiAction<'a>) -> Self {
+        Self { action }
+    }
+}
+
+/// Returns the emoji, if the grapheme cluster is one.
+#[must_use]
+fn emoji(cluster: &str) -> Option<&'static Emoji> {
+    let emoji = emojis::get(cluster)?;
+    // the fully qualified form of a single character needs the variation selector,
+    // if it does not have the `Emoji_Presentation` property
+    let mut chars = cluster.chars();
+    let is_text = chars.next().is_some() && chars.next().is_none() && emoji.as_str() != cluster;
+
+    (!is_text).then_some(emoji)
+}
+
+impl<'a> Sanitizer for EmojiPolicy<'a> {
+    type Iter<I: Iterator<Item = char>> = EmojiPolicyIter<'a, I>;
+
+    fn sanitize<I: Iterator<Item = char>>(self, iter: I) -> Self::Iter<I> {
+        EmojiPolicyIter {
+            segmenter: Segmenter::new(iter),
+            action: self.action,
+            cluster: Cluster::new(),
+            replacement: "".chars(),
+            suffix: None,
+        }
+    }
+
+    fn reason(&self) -> Reason {
+        Reason::Emoji
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct EmojiPolicyIter<'a, I: Iterator<Item = char>> {
+    segmenter: Segmenter<I>,
+    action: EmojiAction<'a>,
+    /// the remaining characters of the current cluster
+    cluster: Cluster,
+    /// the remaining characters of the current replacement
+    replacement: Chars<'a>,
+    /// the colon after a short name
+    suffix: Option<char>,
+}
+
+impl<I: Iterator<Item = char>> Iterator for EmojiPolicyIter<'_, I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(c) = self
+            .cluster
+            .next()
+            .or_else(|| self.replacement.next())
+            .or_else(|| self.suffix.take())
+        {
+            return Some(c);
+        }
+
+        while self.segmenter.next_cluster(&mut self.cluster) {
+            let emoji = match (self.action, emoji(self.cluster.as_str())) {
+                (EmojiAction::Keep, _) | (_, None) => return self.cluster.next(),
+                (_, Some(emoji)) => emoji,
+            };
+
+            self.cluster.clear();
+            match self.action {
+                EmojiAction::Replace(replacement) => self.replacement = replacement.chars(),
+                EmojiAction::ShortName => {
+                    self.replacement = emoji.name().chars();
+                    self.suffix = Some(':');
+                    return Some(':');
+                }
+                EmojiAction::Keep | EmojiAction::Remove => {}
+            }
+
+            // an empty replacement removes the emoji
+            if let Some(c) = self.replacement.next() {
+                return Some(c);
+            }
+        }
+
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (cluster_lower, cluster_upper) = self.cluster.size_hint();
+        let (replacement_lower, replacement_upper) = self.replacement.size_hint();
+        let pending_lower = cluster_lower + replacement_lower + usize::from(self.suffix.is_some());
+        let pending_upper = cluster_upper
+            .zip(replacement_upper)
+            .map(|(cluster, replacement)| {
+                cluster + replacement + usize::from(self.suffix.is_some())
+            });
+
+        // every remaining character could be an emoji, which is replaced with a string
+        let remaining = self
+            .segmenter
+            .size_hint()
+            .1
+            .and_then(|remaining| match self.action {
+                EmojiAction::Keep | EmojiAction::Remove => Some(remaining),
+                EmojiAction::Replace(replacement) => {
+                    remaining.checked_mul(replacement.chars().count().max(1))
+                }
+                EmojiAction::ShortName if remaining == 0 => Some(0),
+                EmojiAction::ShortName => None,
+            });
+
+        (
+            pending_lower,
+            pending_upper
+                .zip(remaining)
+                .and_then(|(pending, remaining)| pending.checked_add(remaining)),
+        )
+    }
+}
+
+impl<I: FusedIterator<Item = char>> FusedIterator for EmojiPolicyIter<'_, I> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FAMILY: &str = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+
+    #[test]
+    fn test_keep() {
+        let policy = EmojiPolicy::new(EmojiAction::Keep);
+        assert_iter_eq_str!(policy.sanitize("a\u{1F604}b".chars()), "a\u{1F604}b");
+    }
+
+    #[test]
+    fn test_remove() {
+        let policy = EmojiPolicy::new(EmojiAction::Remove);
+        let iter = policy.sanitize("a\u{1F604}b\u{1F44D}\u{1F3FD}c\u{2764}\u{FE0F}d".chars());
+        assert_iter_eq_str!(iter, "abcd");
+
+        let iter = policy.sanitize(FAMILY.chars());
+        assert_iter_eq_str!(iter, "");
+    }
+
+    #[test]
+    fn test_replace() {
+        let policy = EmojiPolicy::new(EmojiAction::Replace("_"));
+        let iter = policy.sanitize("\u{1F1E9}\u{1F1EA}a\u{00A9}\u{2764}".chars());
+
+        assert_iter_eq_str!(iter, "_a\u{00A9}\u{2764}");
+    }
+
+    #[test]
+    fn test_emoji_presentation() {
+        let policy = EmojiPolicy::new(EmojiAction::Replace("_"));
+
+        // those are displayed as emoji without the variation selector
+        let iter = policy.sanitize("\u{2B50}a\u{2705}b\u{26A1}c\u{231A}".chars());
+        assert_iter_eq_str!(iter, "_a_b_c_");
+
+        // while those are displayed as text, like the chipmunk outside of the BMP
+        let iter = policy.sanitize("\u{263A}a\u{1F43F}b\u{263A}\u{FE0F}".chars());
+        assert_iter_eq_str!(iter, "\u{263A}a\u{1F43F}b_");
+    }
+
+    #[test]
+    fn test_short_name() {
+        let policy = EmojiPolicy::new(EmojiAction::ShortName);
+
+        let iter = policy.sanitize("a\u{1F604}.txt".chars());
+        assert_iter_eq_str!(iter, "a:grinning face with smiling eyes:.txt");
+
+        let iter = policy.sanitize(FAMILY.chars());
+        assert_iter_eq_str!(iter, ":family: man, woman, girl:");
+    }
+}
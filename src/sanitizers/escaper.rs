@@ -0,0 +1,329 @@
+use core::iter::{FusedIterator, Peekable};
+
+use crate::report::Reason;
+use crate::sanitizer::Sanitizer;
+use crate::utils::Buffer;
+
+/// The character that starts an escape sequence like `%2F`.
+pub const ESCAPE_CHARACTER: char = '%';
+
+const HEX_DIGITS: [char; 16] = [
+    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'A', 'B', 'C', 'D', 'E', 'F',
+];
+
+/// Reversible alternative to replacing characters, which percent-encodes the UTF-8 bytes of
+/// every character where `is_escaped` returns true, for example `?` becomes `%3F`.
+///
+/// Additionally the following characters are escaped:
+/// - the [`ESCAPE_CHARACTER`] itself
+/// - a trailing `.` or space
+/// - the last character of a reserved name (compared case-insensitive), if it is followed by
+///   nothing or a `.`, so `NUL.txt` becomes `NU%4C.txt`
+///
+/// The original string can be restored with the [`Unescaper`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Escaper<F, C: Iterator<Item = char>, const N: usize> {
+    is_escaped: F,
+    reserved: [C; N],
+}
+
+impl<F: FnMut(char) -> bool, C: Iterator<Item = char>, const N: usize> Escaper<F, C, N> {
+    #[must_use]
+    pub const fn new(is_escaped: F, reserved: [C; N]) -> Self {
+        Self {
+            is_escaped,
+            reserved,
+        }
+    }
+}
+
+impl<F, C, const N: usize> Sanitizer for Escaper<F, C, N>
+where
+    F: FnMut(char) -> bool,
+    C: Iterator<Item = char>,
+{
+    type Iter<I: Iterator<Item = char>> = EscaperIter<I, F, C, N>;
+
+    fn sanitize<I: Iterator<Item = char>>(self, iter: I) -> Self::Iter<I> {
+        EscaperIter::new(iter, self.is_escaped, self.reserved)
+    }
+
+    fn reason(&self) -> Reason {
+        Reason::Escaped
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct EscaperIter<I, F, C, const N: usize>
+where
+    I: Iterator<Item = char>,
+    F: FnMut(char) -> bool,
+    C: Iterator<Item = char>,
+{
+    iter: Peekable<I>,
+    is_escaped: F,
+    reserved: [Peekable<C>; N],
+    buffer: Buffer<12>,
+}
+
+impl<I, F, C, const N: usize> EscaperIter<I, F, C, N>
+where
+    I: Iterator<Item = char>,
+    F: FnMut(char) -> bool,
+    C: Iterator<Item = char>,
+{
+    #[must_use]
+    fn new(iter: I, is_escaped: F, reserved: [C; N]) -> Self {
+        Self {
+            iter: iter.peekable(),
+            is_escaped,
+            reserved: reserved.map(Iterator::peekable),
+            buffer: Buffer::new(),
+        }
+    }
+
+    fn escape(&mut self, c: char) {
+        let mut bytes = [0; 4];
+
+        for byte in c.encode_utf8(&mut bytes).bytes() {
+            self.buffer.push(ESCAPE_CHARACTER);
+            self.buffer.push(HEX_DIGITS[usize::from(byte >> 4)]);
+            self.buffer.push(HEX_DIGITS[usize::from(byte & 0xF)]);
+        }
+    }
+}
+
+impl<I, F, C, const N: usize> Iterator for EscaperIter<I, F, C, N>
+where
+    I: Iterator<Item = char>,
+    F: FnMut(char) -> bool,
+    C: Iterator<Item = char>,
+{
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(c) = self.buffer.next() {
+            return Some(c);
+        }
+
+        let c = self.iter.next()?;
+
+        let mut is_reserved = false;
+        for chars in &mut self.reserved {
+            if let Some(r) = chars.next() {
+                if !r.eq_ignore_ascii_case(&c) {
+                    // exhaust the iterator, because it does not match the input
+                    chars.for_each(drop);
+                } else if chars.peek().is_none() {
+                    is_reserved = true;
+                }
+            }
+        }
+
+        let next = self.iter.peek().copied();
+        if c == ESCAPE_CHARACTER
+            || (self.is_escaped)(c)
+            || (is_reserved && matches!(next, None | Some('.')))
+            || (next.is_none() && matches!(c, '.' | ' '))
+        {
+            self.escape(c);
+            return self.buffer.next();
+        }
+
+        Some(c)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower_bound, upper_bound) = self.iter.size_hint();
+        let buffered = self.buffer.len();
+
+        // an escaped character results in up to 4 escape sequences of 3 characters
+        (
+            lower_bound.saturating_add(buffered),
+            upper_bound
+                .and_then(|upper_bound| upper_bound.checked_mul(12))
+                .and_then(|upper_bound| upper_bound.checked_add(buffered)),
+        )
+    }
+}
+
+impl<I, F, C, const N: usize> FusedIterator for EscaperIter<I, F, C, N>
+where
+    I: FusedIterator<Item = char>,
+    F: FnMut(char) -> bool,
+    C: Iterator<Item = char>,
+{
+}
+
+/// Restores the original string from the output of an [`Escaper`].
+///
+/// Malformed escape sequences are kept as they are.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Unescaper {}
+
+impl Sanitizer for Unescaper {
+    type Iter<I: Iterator<Item = char>> = UnescaperIter<I>;
+
+    fn sanitize<I: Iterator<Item = char>>(self, iter: I) -> Self::Iter<I> {
+        UnescaperIter::new(iter)
+    }
+
+    fn reason(&self) -> Reason {
+        Reason::Escaped
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct UnescaperIter<I: Iterator<Item = char>> {
+    iter: Peekable<I>,
+    buffer: Buffer<12>,
+}
+
+impl<I: Iterator<Item = char>> UnescaperIter<I> {
+    #[must_use]
+    fn new(iter: I) -> Self {
+        Self {
+            iter: iter.peekable(),
+            buffer: Buffer::new(),
+        }
+    }
+
+    /// Reads the two hex digits of an escape sequence, the consumed characters are
+    /// stored in the buffer, so they can be emitted if the sequence is malformed.
+    fn read_byte(&mut self) -> Option<u8> {
+        let mut byte = 0;
+
+        for _ in 0..2 {
+            let c = self.iter.next_if(char::is_ascii_hexdigit)?;
+            self.buffer.push(c);
+            byte = (byte << 4) | c.to_digit(16).and_then(|d| u8::try_from(d).ok())?;
+        }
+
+        Some(byte)
+    }
+
+    fn read_char(&mut self) -> Option<char> {
+        let mut bytes = [0; 4];
+        bytes[0] = self.read_byte()?;
+
+        let width = match bytes[0] {
+            0xC0..=0xDF => 2,
+            0xE0..=0xEF => 3,
+            0xF0..=0xF7 => 4,
+            _ => 1,
+        };
+
+        for byte in bytes.iter_mut().take(width).skip(1) {
+            let c = self.iter.next_if_eq(&ESCAPE_CHARACTER)?;
+            self.buffer.push(c);
+            *byte = self.read_byte()?;
+        }
+
+        core::str::from_utf8(&bytes[..width])
+            .ok()
+            .and_then(|s| s.chars().next())
+    }
+}
+
+impl<I: Iterator<Item = char>> Iterator for UnescaperIter<I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(c) = self.buffer.next() {
+            return Some(c);
+        }
+
+        let c = self.iter.next()?;
+        if c != ESCAPE_CHARACTER {
+            return Some(c);
+        }
+
+        self.buffer.push(c);
+        if let Some(c) = self.read_char() {
+            self.buffer.clear();
+            return Some(c);
+        }
+
+        self.buffer.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower_bound, upper_bound) = self.iter.size_hint();
+        let buffered = self.buffer.len();
+
+        // up to 12 characters are unescaped into a single character
+        (
+            lower_bound.div_ceil(12).saturating_add(buffered),
+            upper_bound.and_then(|upper_bound| upper_bound.checked_add(buffered)),
+        )
+    }
+}
+
+impl<I: FusedIterator<Item = char>> FusedIterator for UnescaperIter<I> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use core::str::Chars;
+
+    fn escaper() -> Escaper<fn(char) -> bool, Chars<'static>, 2> {
+        Escaper::new(
+            |c| matches!(c, '/' | '?' | 'ä'),
+            ["NUL", "CON"].map(str::chars),
+        )
+    }
+
+    #[test]
+    fn test_escape() {
+        let iter = escaper().sanitize("a/b?%.txt".chars());
+        assert_iter_eq_str!(iter, "a%2Fb%3F%25.txt");
+    }
+
+    #[test]
+    fn test_escape_multibyte() {
+        let iter = escaper().sanitize("bär".chars());
+        assert_iter_eq_str!(iter, "b%C3%A4r");
+    }
+
+    #[test]
+    fn test_escape_trailing() {
+        let iter = escaper().sanitize("a. b. ".chars());
+        assert_iter_eq_str!(iter, "a. b.%20");
+
+        let iter = escaper().sanitize("..".chars());
+        assert_iter_eq_str!(iter, ".%2E");
+    }
+
+    #[test]
+    fn test_escape_reserved() {
+        assert_iter_eq_str!(escaper().sanitize("NUL".chars()), "NU%4C");
+        assert_iter_eq_str!(escaper().sanitize("con.txt".chars()), "co%6E.txt");
+        assert_iter_eq_str!(escaper().sanitize("NULL".chars()), "NULL");
+        assert_iter_eq_str!(escaper().sanitize("a.NUL".chars()), "a.NUL");
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        for string in ["", "NUL.txt", "a/b?%.", "bär%%20 ", "%C3", "."] {
+            let iter = Unescaper::default().sanitize(escaper().sanitize(string.chars()));
+            assert_iter_eq_str!(iter, string);
+        }
+    }
+
+    #[test]
+    fn test_unescape_malformed() {
+        for string in ["%", "%2", "%G0", "%C3%", "%C3%41", "%FF", "100%"] {
+            let iter = Unescaper::default().sanitize(string.chars());
+            assert_iter_eq_str!(iter, string);
+        }
+    }
+
+    #[test]
+    fn test_unescape_lowercase() {
+        let iter = Unescaper::default().sanitize("b%c3%a4r%2f".chars());
+        assert_iter_eq_str!(iter, "bär/");
+    }
+}
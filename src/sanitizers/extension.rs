@@ -0,0 +1,354 @@
+use core::iter::FusedIterator;
+use core::mem;
+use core::str::Chars;
+
+use crate::report::Reason;
+use crate::sanitizer::Sanitizer;
+use crate::utils::Buffer;
+
+/// The extension (including the dot) is buffered until it is known whether it is dangerous,
+/// so longer extensions can not be matched.
+const MAX_EXTENSION_LENGTH: usize = 16;
+
+/// What an [`ExtensionPolicy`] should do with a dangerous extension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ExtensionAction<'a> {
+    /// Appends the string to the name, for example `photo.jpg.exe` becomes `photo.jpg.exe.txt`
+    /// with `Append(".txt")`.
+    Append(&'a str),
+    /// Replaces the extension, for example `photo.jpg.exe` becomes `photo.jpg.txt` with `Replace("txt")`.
+    Replace(&'a str),
+}
+
+/// Prevents names like `photo.jpg.exe` or `readme.txt.lnk`, which would be executed on Windows,
+/// by applying the [`ExtensionAction`] if the last extension is in the `deny` list.
+///
+/// The extensions are compared case-insensitive and must not include the dot,
+/// extensions with more than 15 characters are never matched.
+///
+/// Trailing dots and spaces are ignored, because Windows removes them, so `photo.exe.` and `photo.exe `
+/// are denied as well.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ExtensionPolicy<'a, const N: usize> {
+    deny: [&'a str; N],
+    action: ExtensionAction<'a>,
+}
+
+impl<'a, const N: usize> ExtensionPolicy<'a, N> {
+    #[must_use]
+    pub const fn new(deny: [&'a str; N], action: ExtensionAction<'a>) -> Self {
+        Self { deny, action }
+    }
+
+    /// Returns the extension of the name, if it is in the `deny` list.
+    #[must_use]
+    pub fn check<'b>(&self, name: &'b str) -> Option<&'b str> {
+        let (_, extension) = name.trim_end_matches(['.', ' ']).rsplit_once('.')?;
+
+        self.deny
+            .iter()
+            .any(|deny| deny.eq_ignore_ascii_case(extension))
+            .then_some(extension)
+    }
+}
+
+impl<'a, const N: usize> Sanitizer for ExtensionPolicy<'a, N> {
+    type Iter<I: Iterator<Item = char>> = ExtensionPolicyIter<'a, I, N>;
+
+    fn sanitize<I: Iterator<Item = char>>(self, iter: I) -> Self::Iter<I> {
+        ExtensionPolicyIter {
+            iter,
+            policy: self,
+            extension: Buffer::new(),
+            output: Buffer::new(),
+            next: None,
+            suffix: "".chars(),
+            is_trailing: false,
+            is_done: false,
+        }
+    }
+
+    fn reason(&self) -> Reason {
+        Reason::Extension
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ExtensionPolicyIter<'a, I: Iterator<Item = char>, const N: usize> {
+    iter: I,
+    policy: ExtensionPolicy<'a, N>,
+    /// the last extension (starting with the dot), which has not been returned yet,
+    /// followed by the trailing dots and spaces if it is denied
+    extension: Buffer<MAX_EXTENSION_LENGTH>,
+    /// the characters that are returned before reading more input
+    output: Buffer<MAX_EXTENSION_LENGTH>,
+    next: Option<char>,
+    suffix: Chars<'a>,
+    /// true if a denied extension and more trailing dots and spaces than fit into the buffer
+    /// have been returned, so the action is applied at the end of the name
+    is_trailing: bool,
+    is_done: bool,
+}
+
+impl<I: Iterator<Item = char>, const N: usize> ExtensionPolicyIter<'_, I, N> {
+    fn is_denied(&self) -> bool {
+        let mut extension = self.extension.as_slice();
+        // Windows removes trailing dots and spaces
+        while let [rest @ .., '.' | ' '] = extension {
+            extension = rest;
+        }
+
+        match extension {
+            ['.', extension @ ..] => self.policy.deny.iter().any(|deny| {
+                deny.chars().count() == extension.len()
+                    && deny
+                        .chars()
+                        .zip(extension)
+                        .all(|(a, b)| a.eq_ignore_ascii_case(b))
+            }),
+            _ => false,
+        }
+    }
+
+    /// Applies the action to the buffered extension.
+    fn apply(&mut self) {
+        match self.policy.action {
+            ExtensionAction::Append(suffix) => {
+                self.output = mem::take(&mut self.extension);
+                self.suffix = suffix.chars();
+            }
+            ExtensionAction::Replace(extension) => {
+                self.extension.clear();
+                self.output.push('.');
+                self.suffix = extension.chars();
+            }
+        }
+    }
+
+    fn finish(&mut self) {
+        self.is_done = true;
+
+        // the extension has already been returned after too many trailing characters,
+        // so the action is applied after those
+        if self.is_trailing || self.is_denied() {
+            self.apply();
+        } else {
+            self.output = mem::take(&mut self.extension);
+        }
+    }
+
+    /// Moves the part of the extension before the last dot to the output, because the trailing dots
+    /// of a denied extension are not trailing anymore, once another character follows.
+    fn split_last_dot(&mut self) {
+        let extension = mem::take(&mut self.extension);
+        let last_dot = extension
+            .as_slice()
+            .iter()
+            .rposition(|c| *c == '.')
+            .unwrap_or_default();
+
+        for (i, c) in extension.enumerate() {
+            if i < last_dot {
+                self.output.push(c);
+            } else {
+                self.extension.push(c);
+            }
+        }
+    }
+}
+
+impl<I: Iterator<Item = char>, const N: usize> Iterator for ExtensionPolicyIter<'_, I, N> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(c) = self
+                .output
+                .next()
+                .or_else(|| self.suffix.next())
+                .or_else(|| self.next.take())
+            {
+                return Some(c);
+            }
+
+            if self.is_done {
+                return None;
+            }
+
+            match self.iter.next() {
+                Some(c @ ('.' | ' ')) if self.is_trailing => return Some(c),
+                Some(c @ ('.' | ' ')) if self.is_denied() => {
+                    if self.extension.len() < MAX_EXTENSION_LENGTH {
+                        self.extension.push(c);
+                    } else {
+                        // too many trailing characters to buffer, so those are returned
+                        // and the action is only applied, if the name ends with them
+                        self.output = mem::take(&mut self.extension);
+                        self.next = Some(c);
+                        self.is_trailing = true;
+                    }
+                }
+                Some('.') => {
+                    self.output = mem::take(&mut self.extension);
+                    self.extension.push('.');
+                }
+                Some(c) if self.extension.is_empty() => {
+                    self.is_trailing = false;
+                    return Some(c);
+                }
+                Some(c) => {
+                    self.split_last_dot();
+
+                    if self.extension.len() < MAX_EXTENSION_LENGTH {
+                        self.extension.push(c);
+                    } else {
+                        // the extension is too long to be denied
+                        self.output = mem::take(&mut self.extension);
+                        self.next = Some(c);
+                    }
+                }
+                None => self.finish(),
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = self.output.len() + usize::from(self.next.is_some());
+        let (suffix_lower_bound, suffix_upper_bound) = self.suffix.size_hint();
+        let lower_bound = pending + suffix_lower_bound;
+        let pending = suffix_upper_bound.map(|upper_bound| pending + upper_bound);
+
+        if self.is_done {
+            return (lower_bound, pending);
+        }
+
+        // a denied extension could be replaced with a dot and the new extension
+        // or the suffix could be appended at the end of the name
+        let suffix = match self.policy.action {
+            ExtensionAction::Append(suffix) => suffix.chars().count(),
+            ExtensionAction::Replace(extension) => extension.chars().count() + 1,
+        };
+        let upper_bound = self.iter.size_hint().1.and_then(|upper_bound| {
+            upper_bound
+                .checked_add(self.extension.len())?
+                .checked_add(suffix)?
+                .checked_add(pending?)
+        });
+
+        (lower_bound, upper_bound)
+    }
+}
+
+// the input is not read anymore, once it has ended, so this does not depend on `I`
+impl<I: Iterator<Item = char>, const N: usize> FusedIterator for ExtensionPolicyIter<'_, I, N> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    const DENY: [&str; 3] = ["exe", "lnk", "scr"];
+
+    #[test]
+    fn test_append() {
+        let policy = ExtensionPolicy::new(DENY, ExtensionAction::Append(".txt"));
+
+        assert_iter_eq_str!(
+            policy.sanitize("photo.jpg.exe".chars()),
+            "photo.jpg.exe.txt"
+        );
+        assert_iter_eq_str!(
+            policy.sanitize("readme.txt.LNK".chars()),
+            "readme.txt.LNK.txt"
+        );
+        assert_iter_eq_str!(policy.sanitize("photo.jpg".chars()), "photo.jpg");
+        assert_iter_eq_str!(policy.sanitize("exe".chars()), "exe");
+        assert_iter_eq_str!(policy.sanitize("a.exe.jpg".chars()), "a.exe.jpg");
+        assert_iter_eq_str!(policy.sanitize("a.exes".chars()), "a.exes");
+    }
+
+    #[test]
+    fn test_replace() {
+        let policy = ExtensionPolicy::new(DENY, ExtensionAction::Replace("txt"));
+
+        assert_iter_eq_str!(policy.sanitize("photo.jpg.Exe".chars()), "photo.jpg.txt");
+        assert_iter_eq_str!(policy.sanitize(".scr".chars()), ".txt");
+        assert_iter_eq_str!(policy.sanitize("photo.jpg.".chars()), "photo.jpg.");
+    }
+
+    #[test]
+    fn test_long_extension() {
+        let policy = ExtensionPolicy::new(DENY, ExtensionAction::Replace("txt"));
+        let string = "a.0123456789abcdefghij.exe";
+
+        assert_iter_eq_str!(
+            policy.sanitize(string.chars()),
+            "a.0123456789abcdefghij.txt"
+        );
+        assert_iter_eq_str!(
+            policy.sanitize("a.0123456789abcdefghij".chars()),
+            "a.0123456789abcdefghij"
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        let policy = ExtensionPolicy::new(DENY, ExtensionAction::Replace("txt"));
+
+        assert_eq!(policy.check("photo.jpg.EXE"), Some("EXE"));
+        assert_eq!(policy.check("photo.jpg"), None);
+        assert_eq!(policy.check("exe"), None);
+        assert_eq!(policy.check("photo.jpg.exe."), Some("exe"));
+        assert_eq!(policy.check("photo.exe "), Some("exe"));
+        assert_eq!(policy.check("photo.exe . "), Some("exe"));
+        assert_eq!(policy.check("photo.exe.png"), None);
+    }
+
+    #[test]
+    fn test_trailing_dots_and_spaces() {
+        let append = ExtensionPolicy::new(DENY, ExtensionAction::Append(".txt"));
+        let replace = ExtensionPolicy::new(DENY, ExtensionAction::Replace("txt"));
+
+        assert_iter_eq_str!(
+            append.sanitize("photo.jpg.exe.".chars()),
+            "photo.jpg.exe..txt"
+        );
+        assert_iter_eq_str!(append.sanitize("photo.exe ".chars()), "photo.exe .txt");
+        assert_iter_eq_str!(replace.sanitize("photo.jpg.exe.".chars()), "photo.jpg.txt");
+        assert_iter_eq_str!(replace.sanitize("photo.exe ".chars()), "photo.txt");
+        assert_iter_eq_str!(replace.sanitize("photo.exe . ".chars()), "photo.txt");
+
+        assert_iter_eq_str!(append.sanitize("a.exe.png".chars()), "a.exe.png");
+        assert_iter_eq_str!(append.sanitize("a.exe x".chars()), "a.exe x");
+        assert_iter_eq_str!(replace.sanitize("a.exe..png".chars()), "a.exe..png");
+    }
+
+    #[test]
+    fn test_many_trailing_dots() {
+        let policy = ExtensionPolicy::new(DENY, ExtensionAction::Replace("txt"));
+        let string = "a.exe................";
+
+        // the extension has already been returned, when the name ends
+        assert_iter_eq_str!(policy.sanitize(string.chars()), "a.exe.................txt");
+
+        let string = "a.exe................x";
+        assert_iter_eq_str!(policy.sanitize(string.chars()), string);
+
+        let policy = ExtensionPolicy::new(DENY, ExtensionAction::Append(".txt"));
+        let string = "a.exe. . . . . . . . ";
+        assert_iter_eq_str!(policy.sanitize(string.chars()), "a.exe. . . . . . . . .txt");
+    }
+
+    #[test]
+    fn test_size_hint() {
+        let policy = ExtensionPolicy::new(DENY, ExtensionAction::Append(".txt"));
+        let mut iter = policy.sanitize("a.exe".chars());
+        assert_eq!(iter.size_hint(), (0, Some(9)));
+
+        assert_eq!(iter.next(), Some('a'));
+        assert_eq!(iter.next(), Some('.'));
+        assert_eq!(iter.size_hint(), (4, Some(7)));
+    }
+}
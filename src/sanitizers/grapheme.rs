@@ -0,0 +1,199 @@
+use core::iter::FusedIterator;
+
+use crate::report::Reason;
+use crate::sanitizer::Sanitizer;
+use crate::utils::{Cluster, Segmenter};
+
+/// Removes duplicate grapheme clusters, by only keeping the first cluster
+/// in a chain of multiple clusters where the closure returns true.
+///
+/// This is the same as the [`Deduplicator`](crate::sanitizers::Deduplicator), except that a
+/// character and its combining characters are either kept or removed together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GraphemeDeduplicator<F>(F);
+
+impl<F: FnMut(&str) -> bool> GraphemeDeduplicator<F> {
+    #[must_use]
+    pub const fn new(f: F) -> Self {
+        Self(f)
+    }
+}
+
+impl<F: FnMut(&str) -> bool> Sanitizer for GraphemeDeduplicator<F> {
+    type Iter<I: Iterator<Item = char>> = GraphemeDeduplicatorIter<F, I>;
+
+    fn sanitize<I: Iterator<Item = char>>(self, iter: I) -> Self::Iter<I> {
+        GraphemeDeduplicatorIter {
+            f: self.0,
+            segmenter: Segmenter::new(iter),
+            cluster: Cluster::new(),
+            flag: false,
+        }
+    }
+
+    fn reason(&self) -> Reason {
+        Reason::Duplicate
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GraphemeDeduplicatorIter<F: FnMut(&str) -> bool, I: Iterator<Item = char>> {
+    f: F,
+    segmenter: Segmenter<I>,
+    /// the remaining characters of the current cluster
+    cluster: Cluster,
+    flag: bool,
+}
+
+impl<F: FnMut(&str) -> bool, I: Iterator<Item = char>> Iterator for GraphemeDeduplicatorIter<F, I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(c) = self.cluster.next() {
+            return Some(c);
+        }
+
+        while self.segmenter.next_cluster(&mut self.cluster) {
+            if !(self.f)(self.cluster.as_str()) {
+                self.flag = false;
+                return self.cluster.next();
+            }
+
+            if !self.flag {
+                self.flag = true;
+                return self.cluster.next();
+            }
+        }
+
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower_bound, upper_bound) = self.cluster.size_hint();
+
+        // the remaining clusters could be duplicates
+        (
+            lower_bound,
+            self.segmenter
+                .size_hint()
+                .1
+                .and_then(|remaining| upper_bound?.checked_add(remaining)),
+        )
+    }
+}
+
+impl<F, I> FusedIterator for GraphemeDeduplicatorIter<F, I>
+where
+    F: FnMut(&str) -> bool,
+    I: FusedIterator<Item = char>,
+{
+}
+
+/// Truncates the name to at most `max_len` bytes, when it is encoded as UTF-8,
+/// without splitting a grapheme cluster like `e\u{301}` or a family emoji.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GraphemeTruncate {
+    max_len: usize,
+}
+
+impl GraphemeTruncate {
+    #[must_use]
+    pub const fn new(max_len: usize) -> Self {
+        Self { max_len }
+    }
+}
+
+impl Sanitizer for GraphemeTruncate {
+    type Iter<I: Iterator<Item = char>> = GraphemeTruncateIter<I>;
+
+    fn sanitize<I: Iterator<Item = char>>(self, iter: I) -> Self::Iter<I> {
+        GraphemeTruncateIter {
+            segmenter: Segmenter::new(iter),
+            cluster: Cluster::new(),
+            remaining: self.max_len,
+        }
+    }
+
+    fn reason(&self) -> Reason {
+        Reason::Truncated
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GraphemeTruncateIter<I: Iterator<Item = char>> {
+    segmenter: Segmenter<I>,
+    /// the remaining characters of the current cluster
+    cluster: Cluster,
+    /// the number of bytes, that can still be emitted
+    remaining: usize,
+}
+
+impl<I: Iterator<Item = char>> Iterator for GraphemeTruncateIter<I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(c) = self.cluster.next() {
+            return Some(c);
+        }
+
+        if !self.segmenter.next_cluster(&mut self.cluster) {
+            return None;
+        }
+
+        if self.cluster.len() > self.remaining {
+            // nothing fits anymore, once a cluster has been cut off
+            self.remaining = 0;
+            self.cluster.clear();
+            return None;
+        }
+
+        self.remaining -= self.cluster.len();
+        self.cluster.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower_bound, upper_bound) = self.cluster.size_hint();
+
+        // every character needs at least one byte
+        let remaining = self
+            .segmenter
+            .size_hint()
+            .1
+            .map_or(self.remaining, |remaining| remaining.min(self.remaining));
+
+        (
+            lower_bound,
+            upper_bound.map(|upper_bound| upper_bound + remaining),
+        )
+    }
+}
+
+impl<I: FusedIterator<Item = char>> FusedIterator for GraphemeTruncateIter<I> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deduplicate() {
+        let sanitizer = GraphemeDeduplicator::new(|s: &str| s.starts_with('_'));
+        let iter = sanitizer.sanitize("a__\u{301}_b_\u{301}_".chars());
+
+        assert_iter_eq_str!(iter, "a_b_\u{301}");
+    }
+
+    #[test]
+    fn test_truncate() {
+        let iter = GraphemeTruncate::new(4).sanitize("abe\u{301}".chars());
+        assert_iter_eq_str!(iter, "ab");
+
+        let iter = GraphemeTruncate::new(5).sanitize("abe\u{301}c".chars());
+        assert_iter_eq_str!(iter, "abe\u{301}");
+
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        let iter = GraphemeTruncate::new(family.len() - 1).sanitize(family.chars());
+        assert_iter_eq_str!(iter, "");
+    }
+}
@@ -0,0 +1,198 @@
+use core::iter::FusedIterator;
+
+use crate::report::Reason;
+use crate::sanitizer::Sanitizer;
+
+/// The maximum number of characters of the `prefix` and of the `suffix` of an [`InfixPadder`].
+const MAX_LEN: usize = 8;
+
+/// Inserts the padding character between `prefix` and `suffix`, wherever both appear together
+/// in the name, compared case-insensitive for ASCII.
+///
+/// `OneDrive` does not allow `_vti_` anywhere in a name, so `a_vti_b` becomes `a_vt_i_b`
+/// with the prefix `_vt`, the suffix `i_` and the padding `_`.
+/// The infix must be split, so the padding can not form it again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct InfixPadder<'a> {
+    prefix: &'a str,
+    suffix: &'a str,
+    padding: char,
+}
+
+impl<'a> InfixPadder<'a> {
+    /// # Panics
+    ///
+    /// If the `prefix` or the `suffix` is longer than 8 bytes.
+    #[must_use]
+    pub const fn new(prefix: &'a str, suffix: &'a str, padding: char) -> Self {
+        assert!(prefix.len() <= MAX_LEN && suffix.len() <= MAX_LEN);
+
+        Self {
+            prefix,
+            suffix,
+            padding,
+        }
+    }
+}
+
+impl<'a> Sanitizer for InfixPadder<'a> {
+    type Iter<I: Iterator<Item = char>> = InfixPadderIter<'a, I>;
+
+    fn sanitize<I: Iterator<Item = char>>(self, iter: I) -> Self::Iter<I> {
+        InfixPadderIter {
+            iter,
+            padder: self,
+            emitted: Window::default(),
+            lookahead: Window::default(),
+            padded: false,
+        }
+    }
+
+    fn reason(&self) -> Reason {
+        Reason::ReservedName
+    }
+}
+
+/// The last characters, which have been returned, or the next characters of the name.
+#[derive(Debug, Clone, Copy, Default)]
+struct Window {
+    chars: [char; MAX_LEN],
+    len: usize,
+}
+
+impl Window {
+    /// Appends the character, the first one is dropped if the window already has `max_len` characters.
+    fn push(&mut self, c: char, max_len: usize) {
+        if max_len == 0 {
+            return;
+        }
+
+        if self.len == max_len {
+            self.chars.copy_within(1..self.len, 0);
+            self.len -= 1;
+        }
+
+        self.chars[self.len] = c;
+        self.len += 1;
+    }
+
+    fn pop_front(&mut self) -> Option<char> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let c = self.chars[0];
+        self.chars.copy_within(1..self.len, 0);
+        self.len -= 1;
+        Some(c)
+    }
+
+    fn equals(&self, string: &str) -> bool {
+        self.len == string.chars().count()
+            && self.chars[..self.len]
+                .iter()
+                .zip(string.chars())
+                .all(|(a, b)| a.eq_ignore_ascii_case(&b))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct InfixPadderIter<'a, I: Iterator<Item = char>> {
+    iter: I,
+    padder: InfixPadder<'a>,
+    /// the end of the name, which has already been returned
+    emitted: Window,
+    /// the characters after `emitted`, which are compared with the suffix
+    lookahead: Window,
+    /// true if the padding has just been returned
+    padded: bool,
+}
+
+impl<I: Iterator<Item = char>> Iterator for InfixPadderIter<'_, I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let prefix_len = self.padder.prefix.chars().count();
+        let suffix_len = self.padder.suffix.chars().count();
+
+        while self.lookahead.len < suffix_len {
+            let Some(c) = self.iter.next() else {
+                break;
+            };
+            self.lookahead.push(c, suffix_len);
+        }
+
+        let c = if !self.padded
+            && self.emitted.equals(self.padder.prefix)
+            && self.lookahead.equals(self.padder.suffix)
+        {
+            self.padded = true;
+            self.padder.padding
+        } else {
+            self.padded = false;
+            match self.lookahead.pop_front() {
+                Some(c) => c,
+                None => self.iter.next()?,
+            }
+        };
+
+        self.emitted.push(c, prefix_len);
+        Some(c)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower_bound, upper_bound) = self.iter.size_hint();
+        let buffered = self.lookahead.len;
+
+        // at most every character is preceded by a padding character
+        (
+            lower_bound.saturating_add(buffered),
+            upper_bound
+                .and_then(|upper_bound| upper_bound.checked_add(buffered))
+                .and_then(|upper_bound| upper_bound.checked_mul(2)),
+        )
+    }
+}
+
+impl<I: FusedIterator<Item = char>> FusedIterator for InfixPadderIter<'_, I> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_infix() {
+        let padder = InfixPadder::new("_vt", "i_", '_');
+
+        assert_iter_eq_str!(padder.sanitize("_vti_".chars()), "_vt_i_");
+        assert_iter_eq_str!(padder.sanitize("a_VTI_b".chars()), "a_VT_I_b");
+        assert_iter_eq_str!(padder.sanitize("_vti_vti_".chars()), "_vt_i_vt_i_");
+        assert_iter_eq_str!(padder.sanitize("__vti__".chars()), "__vt_i__");
+        assert_iter_eq_str!(padder.sanitize("_vti".chars()), "_vti");
+        assert_iter_eq_str!(padder.sanitize("".chars()), "");
+    }
+
+    #[test]
+    fn test_idempotent() {
+        for padding in ['_', 'i', 't', 'v', 'x'] {
+            let padder = InfixPadder::new("_vt", "i_", padding);
+            let once = padder.sanitize("x_vti_vti__vti_".chars());
+            let twice = padder.sanitize(once.clone());
+
+            assert!(once.eq(twice), "{padding:?}");
+        }
+    }
+
+    #[test]
+    fn test_size_hint() {
+        let padder = InfixPadder::new("_vt", "i_", '_');
+        let iter = padder.sanitize("_vti_".chars());
+        let (lower_bound, upper_bound) = iter.size_hint();
+
+        assert_eq!(iter.count(), 6);
+        assert!(lower_bound <= 6 && upper_bound.is_some_and(|upper_bound| upper_bound >= 6));
+    }
+}
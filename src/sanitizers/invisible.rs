@@ -0,0 +1,127 @@
+use core::iter;
+
+use crate::report::Reason;
+use crate::sanitizer::Sanitizer;
+use crate::sanitizers::{replacement, replacement_for, OptionReplacerIter, Replacer};
+
+/// Returns true for the bidirectional formatting characters and invisible characters,
+/// which can be used to make a name look different from what it actually is.
+///
+/// The zero width joiner and non-joiner (`U+200D` and `U+200C`) are not included,
+/// because they are required by some scripts and emoji sequences.
+#[must_use]
+pub const fn is_invisible(c: char) -> bool {
+    matches!(
+        c,
+        // soft hyphen
+        '\u{00AD}'
+            // arabic letter mark
+            | '\u{061C}'
+            // mongolian vowel separator
+            | '\u{180E}'
+            // zero width space
+            | '\u{200B}'
+            // left-to-right and right-to-left mark
+            | '\u{200E}'..='\u{200F}'
+            // bidirectional embeddings and overrides
+            | '\u{202A}'..='\u{202E}'
+            // word joiner and invisible operators
+            | '\u{2060}'..='\u{2064}'
+            // bidirectional isolates
+            | '\u{2066}'..='\u{2069}'
+            // zero width no-break space (byte order mark)
+            | '\u{FEFF}'
+    )
+}
+
+/// This sanitizer replaces bidirectional formatting characters like `U+202E` and invisible characters
+/// like `U+200B` with the specified replacement character, see [`is_invisible`].
+///
+/// Those can be used to disguise a name, for example `invoice\u{202E}fdp.exe` is displayed as `invoiceexe.pdf`.
+///
+/// If the replacement character is [`REMOVE`](crate::sanitizers::REMOVE), the characters are removed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Invisible<const RP: char> {}
+
+impl<const RP: char> Default for Invisible<RP> {
+    fn default() -> Self {
+        Self {}
+    }
+}
+
+impl<const RP: char> Sanitizer for Invisible<RP> {
+    type Iter<I: Iterator<Item = char>> = iter::FilterMap<I, fn(char) -> Option<char>>;
+
+    fn sanitize<I: Iterator<Item = char>>(self, iter: I) -> Self::Iter<I> {
+        iter.filter_map(|c| {
+            if is_invisible(c) {
+                replacement::<RP>()
+            } else {
+                Some(c)
+            }
+        })
+    }
+
+    fn reason(&self) -> Reason {
+        Reason::InvisibleCharacter
+    }
+}
+
+/// The same as [`Invisible`], but the replacement character is chosen at runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RuntimeInvisible {
+    replacement: Option<char>,
+}
+
+impl RuntimeInvisible {
+    /// If the `replacement` is [`REMOVE`](crate::sanitizers::REMOVE), the characters are removed.
+    #[must_use]
+    pub const fn new(replacement: char) -> Self {
+        Self {
+            replacement: replacement_for(replacement),
+        }
+    }
+}
+
+impl Sanitizer for RuntimeInvisible {
+    type Iter<I: Iterator<Item = char>> = OptionReplacerIter<I, (fn(char) -> bool, Option<char>)>;
+
+    fn sanitize<I: Iterator<Item = char>>(self, iter: I) -> Self::Iter<I> {
+        Replacer::from((is_invisible as fn(char) -> bool, self.replacement)).sanitize(iter)
+    }
+
+    fn reason(&self) -> Reason {
+        Reason::InvisibleCharacter
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sanitizers::REMOVE;
+
+    #[test]
+    fn test_replace() {
+        let sanitizer: Invisible<'_'> = Invisible::default();
+        let iter = sanitizer.sanitize("invoice\u{202E}fdp.exe".chars());
+
+        assert_iter_eq_str!(iter, "invoice_fdp.exe");
+    }
+
+    #[test]
+    fn test_remove() {
+        let sanitizer: Invisible<REMOVE> = Invisible::default();
+        let iter = sanitizer.sanitize("\u{FEFF}a\u{200B}b\u{2066}c\u{2069}\u{200D}".chars());
+
+        assert_iter_eq_str!(iter, "abc\u{200D}");
+    }
+
+    #[test]
+    fn test_runtime() {
+        let iter = RuntimeInvisible::new('_').sanitize("invoice\u{202E}fdp.exe".chars());
+        assert_iter_eq_str!(iter, "invoice_fdp.exe");
+
+        let iter = RuntimeInvisible::new(REMOVE).sanitize("\u{FEFF}a\u{200B}b".chars());
+        assert_iter_eq_str!(iter, "ab");
+    }
+}
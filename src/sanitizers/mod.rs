@@ -1,17 +1,55 @@
+mod category;
 mod control;
 mod deduplicator;
+#[cfg(feature = "emoji")]
+mod emoji;
+mod escaper;
+mod extension;
+#[cfg(feature = "graphemes")]
+mod grapheme;
+mod infix;
+mod invisible;
+#[cfg(feature = "normalization")]
+mod normalize;
 mod padder;
 mod prefix_stripper;
 mod replacer;
+#[cfg(feature = "confusables")]
+mod skeleton;
 mod then;
+mod trailing;
+mod transliterate;
+mod truncate;
 mod whitespace;
 
+pub(crate) use replacer::{replacement, replacement_for};
+
+pub use category::{Categories, GeneralCategory, GeneralCategoryIter, RuntimeGeneralCategory};
 pub use control::Control;
 pub use deduplicator::{Deduplicator, DeduplicatorIter};
-pub use padder::{Padder, PadderIter};
+#[cfg(feature = "emoji")]
+pub use emoji::{EmojiAction, EmojiPolicy, EmojiPolicyIter};
+pub use escaper::{Escaper, EscaperIter, Unescaper, UnescaperIter, ESCAPE_CHARACTER};
+pub use extension::{ExtensionAction, ExtensionPolicy, ExtensionPolicyIter};
+#[cfg(feature = "graphemes")]
+pub use grapheme::{
+    GraphemeDeduplicator, GraphemeDeduplicatorIter, GraphemeTruncate, GraphemeTruncateIter,
+};
+pub use infix::{InfixPadder, InfixPadderIter};
+pub use invisible::{is_invisible, Invisible, RuntimeInvisible};
+#[cfg(feature = "normalization")]
+pub use normalize::{Form, Normalize, NormalizeIter};
+pub use padder::{Padder, PadderIter, RuntimePadder, RuntimePadderIter};
 pub use prefix_stripper::PrefixStripper;
-pub use replacer::{Replacer, ReplacerIter};
+pub use replacer::{
+    OptionReplacerIter, Replacer, ReplacerIter, StrReplacerIter, PRIVATE_USE_OFFSET, REMOVE,
+};
+#[cfg(feature = "confusables")]
+pub use skeleton::{PrototypeIter, Skeleton};
 pub use then::Then;
+pub use trailing::{TrailingReplacer, TrailingReplacerIter};
+pub use transliterate::Transliterate;
+pub use truncate::{Truncate, TruncateIter};
 pub use whitespace::Whitespace;
 
 #[cfg(test)]
@@ -39,4 +77,18 @@ mod tests {
         assert_eq!(sanitizer.next(), Some('o'));
         assert_eq!(sanitizer.next(), None);
     }
+
+    #[test]
+    fn test_stateful_closure() {
+        let mut count = 0;
+        let mut number = |c: char| {
+            count += 1;
+            char::from_digit(count % 10, 10)
+                .filter(|_| c == '#')
+                .or(Some(c))
+        };
+
+        assert_iter_eq_str!((&mut number).sanitize("a#".chars()), "a2");
+        assert_iter_eq_str!((&mut number).sanitize("#b".chars()), "3b");
+    }
 }
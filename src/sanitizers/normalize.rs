@@ -0,0 +1,117 @@
+use core::fmt;
+use core::iter::FusedIterator;
+
+use unicode_normalization::{Decompositions, Recompositions, UnicodeNormalization};
+
+use crate::report::Reason;
+use crate::sanitizer::Sanitizer;
+
+/// The Unicode normalization forms, see <https://unicode.org/reports/tr15/>.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Form {
+    /// Canonical decomposition followed by canonical composition.
+    Nfc,
+    /// Canonical decomposition.
+    Nfd,
+    /// Compatibility decomposition followed by canonical composition.
+    Nfkc,
+    /// Compatibility decomposition.
+    Nfkd,
+}
+
+/// Normalizes the input to the given normalization [`Form`], so visually identical names like
+/// `U+00E9` and `e` + `U+0301` result in the same characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Normalize {
+    form: Form,
+}
+
+impl Normalize {
+    #[must_use]
+    pub const fn new(form: Form) -> Self {
+        Self { form }
+    }
+}
+
+impl Sanitizer for Normalize {
+    type Iter<I: Iterator<Item = char>> = NormalizeIter<I>;
+
+    fn sanitize<I: Iterator<Item = char>>(self, iter: I) -> Self::Iter<I> {
+        match self.form {
+            Form::Nfc => NormalizeIter::Recompositions(iter.nfc()),
+            Form::Nfd => NormalizeIter::Decompositions(iter.nfd()),
+            Form::Nfkc => NormalizeIter::Recompositions(iter.nfkc()),
+            Form::Nfkd => NormalizeIter::Decompositions(iter.nfkd()),
+        }
+    }
+
+    fn reason(&self) -> Reason {
+        Reason::Normalization
+    }
+}
+
+#[derive(Clone)]
+pub enum NormalizeIter<I: Iterator<Item = char>> {
+    Decompositions(Decompositions<I>),
+    Recompositions(Recompositions<I>),
+}
+
+impl<I: Iterator<Item = char>> Iterator for NormalizeIter<I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Decompositions(iter) => iter.next(),
+            Self::Recompositions(iter) => iter.next(),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Self::Decompositions(iter) => iter.size_hint(),
+            Self::Recompositions(iter) => iter.size_hint(),
+        }
+    }
+}
+
+impl<I: FusedIterator<Item = char>> FusedIterator for NormalizeIter<I> {}
+
+/// The iterators of `unicode-normalization` do not implement `Debug`.
+impl<I: Iterator<Item = char>> fmt::Debug for NormalizeIter<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Decompositions(_) => f.debug_struct("Decompositions").finish_non_exhaustive(),
+            Self::Recompositions(_) => f.debug_struct("Recompositions").finish_non_exhaustive(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nfc() {
+        let iter = Normalize::new(Form::Nfc).sanitize("e\u{0301}\u{00E9}".chars());
+        assert_iter_eq_str!(iter, "\u{00E9}\u{00E9}");
+    }
+
+    #[test]
+    fn test_nfd() {
+        let iter = Normalize::new(Form::Nfd).sanitize("e\u{0301}\u{00E9}".chars());
+        assert_iter_eq_str!(iter, "e\u{0301}e\u{0301}");
+    }
+
+    #[test]
+    fn test_nfkc() {
+        let iter = Normalize::new(Form::Nfkc).sanitize("\u{FB01}le\u{FF0F}".chars());
+        assert_iter_eq_str!(iter, "file/");
+    }
+
+    #[test]
+    fn test_nfkd() {
+        let iter = Normalize::new(Form::Nfkd).sanitize("\u{2460}\u{00E9}".chars());
+        assert_iter_eq_str!(iter, "1e\u{0301}");
+    }
+}
@@ -1,62 +1,156 @@
 use core::iter::{FusedIterator, Peekable};
 
+use crate::report::Reason;
 use crate::sanitizer::Sanitizer;
 
+/// Appends the padding character `P` to names, which match one of the `strings`.
+///
+/// With `insert_before` the character is also inserted, if the match is followed by it,
+/// for example `NUL.txt` becomes `NUL_.txt` with `Some('.')`.
+/// An empty string matches the empty name, so it becomes `_`.
+///
+/// See [`RuntimePadder`] for a padding character chosen at runtime.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Padder<I: Iterator<Item = char>, const P: char, const N: usize> {
     strings: [I; N],
     insert_before: Option<char>,
+    ignore_ascii_case: bool,
 }
 
 impl<I: Iterator<Item = char>, const P: char, const N: usize> Padder<I, P, N> {
     #[must_use]
-    pub fn new(strings: [I; N], insert_before: Option<char>) -> Self {
+    pub const fn new(strings: [I; N], insert_before: Option<char>) -> Self {
         Self {
             strings,
             insert_before,
+            ignore_ascii_case: false,
         }
     }
+
+    /// Compares the `strings` ASCII case-insensitive, so `nul` matches `NUL`.
+    #[must_use]
+    pub const fn ignore_ascii_case(mut self) -> Self {
+        self.ignore_ascii_case = true;
+        self
+    }
 }
 
 impl<C: Iterator<Item = char>, const P: char, const N: usize> Sanitizer for Padder<C, P, N> {
     type Iter<I: Iterator<Item = char>> = PadderIter<I, C, P, N>;
 
     fn sanitize<I: Iterator<Item = char>>(self, iter: I) -> Self::Iter<I> {
-        PadderIter::new(iter, self.strings, self.insert_before)
+        PadderIter(
+            RuntimePadder {
+                strings: self.strings,
+                padding: P,
+                insert_before: self.insert_before,
+                ignore_ascii_case: self.ignore_ascii_case,
+            }
+            .sanitize(iter),
+        )
+    }
+
+    fn reason(&self) -> Reason {
+        Reason::ReservedName
     }
 }
 
-// TODO: implement traits
 #[derive(Debug, Clone)]
 pub struct PadderIter<
     I: Iterator<Item = char>,
     C: Iterator<Item = char>,
     const P: char,
     const N: usize,
-> {
-    iter: Peekable<I>,
-    strings: [Peekable<C>; N],
-    matched: bool,
-    insert_before: Option<char>,
-}
+>(RuntimePadderIter<I, C, N>);
 
-impl<I, C, const P: char, const N: usize> PadderIter<I, C, P, N>
+impl<I, C, const P: char, const N: usize> Iterator for PadderIter<I, C, P, N>
 where
     I: Iterator<Item = char>,
     C: Iterator<Item = char>,
 {
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<I, C, const P: char, const N: usize> FusedIterator for PadderIter<I, C, P, N>
+//
+where
+    I: FusedIterator<Item = char>,
+    C: FusedIterator<Item = char>,
+{
+}
+
+/// The same as [`Padder`], but the `padding` character is chosen at runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RuntimePadder<I: Iterator<Item = char>, const N: usize> {
+    strings: [I; N],
+    padding: char,
+    insert_before: Option<char>,
+    ignore_ascii_case: bool,
+}
+
+impl<I: Iterator<Item = char>, const N: usize> RuntimePadder<I, N> {
     #[must_use]
-    fn new(iter: I, strings: [C; N], insert_before: Option<char>) -> Self {
+    pub const fn new(strings: [I; N], padding: char, insert_before: Option<char>) -> Self {
         Self {
-            iter: iter.peekable(),
-            strings: strings.map(Iterator::peekable),
-            matched: false,
+            strings,
+            padding,
             insert_before,
+            ignore_ascii_case: false,
         }
     }
+
+    /// Compares the `strings` ASCII case-insensitive, so `nul` matches `NUL`.
+    #[must_use]
+    pub const fn ignore_ascii_case(mut self) -> Self {
+        self.ignore_ascii_case = true;
+        self
+    }
 }
 
-impl<I, C, const P: char, const N: usize> Iterator for PadderIter<I, C, P, N>
+impl<C: Iterator<Item = char>, const N: usize> Sanitizer for RuntimePadder<C, N> {
+    type Iter<I: Iterator<Item = char>> = RuntimePadderIter<I, C, N>;
+
+    fn sanitize<I: Iterator<Item = char>>(self, iter: I) -> Self::Iter<I> {
+        let mut strings = self.strings.map(Iterator::peekable);
+        // the empty string matches before the first character
+        let matched = strings.iter_mut().any(|chars| chars.peek().is_none());
+
+        RuntimePadderIter {
+            iter: iter.peekable(),
+            strings,
+            matched,
+            padding: self.padding,
+            insert_before: self.insert_before,
+            ignore_ascii_case: self.ignore_ascii_case,
+        }
+    }
+
+    fn reason(&self) -> Reason {
+        Reason::ReservedName
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RuntimePadderIter<I: Iterator<Item = char>, C: Iterator<Item = char>, const N: usize> {
+    iter: Peekable<I>,
+    strings: [Peekable<C>; N],
+    matched: bool,
+    padding: char,
+    insert_before: Option<char>,
+    ignore_ascii_case: bool,
+}
+
+impl<I, C, const N: usize> Iterator for RuntimePadderIter<I, C, N>
 where
     I: Iterator<Item = char>,
     C: Iterator<Item = char>,
@@ -66,13 +160,9 @@ where
     fn next(&mut self) -> Option<Self::Item> {
         let next_c = {
             let peeked = self.iter.peek();
-            if self.matched
-                && peeked
-                    .map(|c| Some(*c) == self.insert_before)
-                    .unwrap_or(true)
-            {
+            if self.matched && peeked.is_none_or(|c| Some(*c) == self.insert_before) {
                 self.matched = false;
-                return Some(P);
+                return Some(self.padding);
             } else if let Some(c) = self.iter.next() {
                 self.matched = false;
                 c
@@ -81,9 +171,15 @@ where
             }
         };
 
-        for chars in self.strings.iter_mut() {
+        for chars in &mut self.strings {
             if let Some(c) = chars.next() {
-                if c != next_c {
+                let is_match = if self.ignore_ascii_case {
+                    c.eq_ignore_ascii_case(&next_c)
+                } else {
+                    c == next_c
+                };
+
+                if !is_match {
                     // exhaust the iterator, because it does not match the input
                     chars.for_each(drop);
                 } else if chars.peek().is_none() {
@@ -100,16 +196,23 @@ where
     fn size_hint(&self) -> (usize, Option<usize>) {
         let (lower_bound, upper_bound) = self.iter.size_hint();
 
-        if let Some(upper_bound) = upper_bound {
-            // at most one padding char will be added
-            (lower_bound, Some(upper_bound + 1))
-        } else {
-            (lower_bound, None)
-        }
+        // every string can only match once, but with `insert_before` multiple strings can match,
+        // like `NUL` and `NUL.txt` in `NUL.txt`
+        let paddings = usize::from(self.matched)
+            + self
+                .strings
+                .iter()
+                .filter(|chars| chars.size_hint().1 != Some(0))
+                .count();
+
+        (
+            lower_bound,
+            upper_bound.and_then(|upper_bound| upper_bound.checked_add(paddings)),
+        )
     }
 }
 
-impl<I, C, const P: char, const N: usize> FusedIterator for PadderIter<I, C, P, N>
+impl<I, C, const N: usize> FusedIterator for RuntimePadderIter<I, C, N>
 //
 where
     I: FusedIterator<Item = char>,
@@ -153,6 +256,17 @@ mod tests {
         assert_eq!(iter.next(), None);
     }
 
+    #[test]
+    fn test_multiple_paddings() {
+        let padder: Padder<Chars<'_>, '_', 2> =
+            Padder::new(["NUL", "NUL.txt"].map(str::chars), Some('.'));
+        let string = "NUL.txt";
+        let iter = padder.sanitize(string.chars());
+
+        assert_eq!(iter.size_hint(), (2, Some(9)));
+        assert_iter_eq_str!(iter, "NUL_.txt_");
+    }
+
     #[test]
     fn test_with_insert_before() {
         let padder: Padder<Chars<'_>, '\u{FFFD}', 3> =
@@ -175,4 +289,46 @@ mod tests {
         assert_eq!(iter.next(), Some('t'));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn test_runtime() {
+        let padder = RuntimePadder::new(["NUL", "CON"].map(str::chars), '-', Some('.'));
+
+        assert_iter_eq_str!(padder.clone().sanitize("NUL.txt".chars()), "NUL-.txt");
+        assert_iter_eq_str!(padder.clone().sanitize("CON".chars()), "CON-");
+        assert_iter_eq_str!(padder.sanitize("CONX".chars()), "CONX");
+    }
+
+    #[test]
+    fn test_runtime_empty() {
+        // the empty string only matches the empty name, not the start of a longer one
+        let padder = RuntimePadder::new(["", "", ""].map(str::chars), '\u{FFFF}', None);
+        assert_iter_eq_str!(padder.clone().sanitize("".chars()), "\u{FFFF}");
+        assert_iter_eq_str!(padder.sanitize("\n".chars()), "\n");
+
+        let padder = RuntimePadder::new(["  ", "", ""].map(str::chars), '\0', None);
+        assert_iter_eq_str!(padder.clone().sanitize("\nA".chars()), "\nA");
+        assert_iter_eq_str!(padder.sanitize("  ".chars()), "  \0");
+    }
+
+    #[test]
+    fn test_empty() {
+        let padder: Padder<Chars<'_>, '_', 3> = Padder::new(["", ".", ".."].map(str::chars), None);
+
+        assert_iter_eq_str!(padder.clone().sanitize("".chars()), "_");
+        assert_iter_eq_str!(padder.clone().sanitize(".".chars()), "._");
+        assert_iter_eq_str!(padder.clone().sanitize("..".chars()), ".._");
+        assert_iter_eq_str!(padder.clone().sanitize("...".chars()), "...");
+        assert_iter_eq_str!(padder.sanitize("a".chars()), "a");
+    }
+
+    #[test]
+    fn test_ignore_ascii_case() {
+        let padder: Padder<Chars<'_>, '_', 2> =
+            Padder::new(["NUL", "desktop.ini"].map(str::chars), Some('.')).ignore_ascii_case();
+
+        assert_iter_eq_str!(padder.clone().sanitize("nul".chars()), "nul_");
+        assert_iter_eq_str!(padder.clone().sanitize("Nul.txt".chars()), "Nul_.txt");
+        assert_iter_eq_str!(padder.sanitize("desktop.INI".chars()), "desktop.INI_");
+    }
 }
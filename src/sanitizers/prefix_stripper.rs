@@ -1,3 +1,6 @@
+use core::iter::FusedIterator;
+
+use crate::report::Reason;
 use crate::sanitizer::Sanitizer;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -7,7 +10,7 @@ pub struct PrefixStripper<P> {
 
 impl<P: FnMut(char) -> bool> PrefixStripper<P> {
     #[must_use]
-    pub fn new(is_prefix: P) -> Self {
+    pub const fn new(is_prefix: P) -> Self {
         Self { is_prefix }
     }
 }
@@ -18,9 +21,13 @@ impl<P: FnMut(char) -> bool> Sanitizer for PrefixStripper<P> {
     fn sanitize<I: Iterator<Item = char>>(self, iter: I) -> Self::Iter<I> {
         PrefixStripperIter::new(iter, self.is_prefix)
     }
+
+    fn reason(&self) -> Reason {
+        Reason::StrippedPrefix
+    }
 }
 
-// TODO: implement traits
+#[derive(Debug, Clone)]
 pub struct PrefixStripperIter<I: Iterator<Item = char>, P: FnMut(char) -> bool> {
     iter: I,
     prefix_ended: bool,
@@ -29,7 +36,7 @@ pub struct PrefixStripperIter<I: Iterator<Item = char>, P: FnMut(char) -> bool>
 
 impl<I: Iterator<Item = char>, P: FnMut(char) -> bool> PrefixStripperIter<I, P> {
     #[must_use]
-    fn new(iter: I, is_prefix: P) -> Self {
+    const fn new(iter: I, is_prefix: P) -> Self {
         Self {
             iter,
             prefix_ended: false,
@@ -55,6 +62,23 @@ impl<I: Iterator<Item = char>, P: FnMut(char) -> bool> Iterator for PrefixStripp
 
         None
     }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.prefix_ended {
+            self.iter.size_hint()
+        } else {
+            // every character could be part of the prefix
+            (0, self.iter.size_hint().1)
+        }
+    }
+}
+
+impl<I, P> FusedIterator for PrefixStripperIter<I, P>
+where
+    I: FusedIterator<Item = char>,
+    P: FnMut(char) -> bool,
+{
 }
 
 #[cfg(test)]
@@ -80,4 +104,14 @@ mod tests {
         assert_eq!(iter.next(), Some(' '));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn test_size_hint() {
+        let mut iter =
+            PrefixStripper::new(char::is_whitespace).sanitize([' ', 'a', 'b'].into_iter());
+        assert_eq!(iter.size_hint(), (0, Some(3)));
+
+        assert_eq!(iter.next(), Some('a'));
+        assert_eq!(iter.size_hint(), (1, Some(1)));
+    }
 }
@@ -1,12 +1,43 @@
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::str::Chars;
+
+use crate::report::Reason;
 use crate::sanitizer::Sanitizer;
-use crate::utils::Map;
+use crate::utils::{Map, Sorted};
+
+/// Using this as replacement character for the platforms, [`Control`](crate::sanitizers::Control)
+/// or [`Whitespace`](crate::sanitizers::Whitespace) removes the characters instead of replacing them.
+pub const REMOVE: char = '\0';
+
+/// Returns the replacement for `RP`, which is `None` if the character should be removed.
+#[must_use]
+pub(crate) const fn replacement<const RP: char>() -> Option<char> {
+    replacement_for(RP)
+}
+
+/// Returns the replacement for the replacement character `c` chosen at runtime,
+/// which is `None` if the character should be removed.
+#[must_use]
+pub(crate) const fn replacement_for(c: char) -> Option<char> {
+    if c == REMOVE {
+        None
+    } else {
+        Some(c)
+    }
+}
 
+/// Replaces characters with the value they are mapped to in `M`.
+///
+/// By default every character is replaced with a single `char`, a `Replacer<M, &str>`
+/// replaces each character with a string, like `&` with `and` and a `Replacer<M, Option<char>>`
+/// removes the characters that are mapped to `None`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct Replacer<M: Map<char, char>>(M);
+pub struct Replacer<M: Map<char, V>, V = char>(M, PhantomData<V>);
 
-impl<M: Map<char, char>> From<M> for Replacer<M> {
+impl<M: Map<char, V>, V> From<M> for Replacer<M, V> {
     fn from(map: M) -> Self {
-        Self(map)
+        Self(map, PhantomData)
     }
 }
 
@@ -16,8 +47,41 @@ impl<M: Map<char, char>> Sanitizer for Replacer<M> {
     fn sanitize<I: Iterator<Item = char>>(self, iter: I) -> Self::Iter<I> {
         ReplacerIter { iter, map: self.0 }
     }
+
+    fn reason(&self) -> Reason {
+        Reason::ForbiddenCharacter
+    }
+}
+
+impl<'a, M: Map<char, &'a str>> Sanitizer for Replacer<M, &'a str> {
+    type Iter<I: Iterator<Item = char>> = StrReplacerIter<'a, I, M>;
+
+    fn sanitize<I: Iterator<Item = char>>(self, iter: I) -> Self::Iter<I> {
+        StrReplacerIter {
+            iter,
+            map: self.0,
+            replacement: "".chars(),
+        }
+    }
+
+    fn reason(&self) -> Reason {
+        Reason::ForbiddenCharacter
+    }
+}
+
+impl<M: Map<char, Option<char>>> Sanitizer for Replacer<M, Option<char>> {
+    type Iter<I: Iterator<Item = char>> = OptionReplacerIter<I, M>;
+
+    fn sanitize<I: Iterator<Item = char>>(self, iter: I) -> Self::Iter<I> {
+        OptionReplacerIter { iter, map: self.0 }
+    }
+
+    fn reason(&self) -> Reason {
+        Reason::ForbiddenCharacter
+    }
 }
 
+#[derive(Debug, Clone)]
 pub struct ReplacerIter<I: Iterator<Item = char>, M: Map<char, char>> {
     iter: I,
     map: M,
@@ -31,4 +95,231 @@ impl<I: Iterator<Item = char>, M: Map<char, char>> Iterator for ReplacerIter<I,
 
         Some(self.map.get(&c).map_or(c, |c| *c))
     }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // every character is replaced with exactly one character
+        self.iter.size_hint()
+    }
+}
+
+impl<I, M> DoubleEndedIterator for ReplacerIter<I, M>
+where
+    I: DoubleEndedIterator<Item = char>,
+    M: Map<char, char>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let c = self.iter.next_back()?;
+
+        Some(self.map.get(&c).map_or(c, |c| *c))
+    }
+}
+
+impl<I: ExactSizeIterator<Item = char>, M: Map<char, char>> ExactSizeIterator
+    for ReplacerIter<I, M>
+{
+}
+
+impl<I: FusedIterator<Item = char>, M: Map<char, char>> FusedIterator for ReplacerIter<I, M> {}
+
+#[derive(Debug, Clone)]
+pub struct OptionReplacerIter<I: Iterator<Item = char>, M: Map<char, Option<char>>> {
+    iter: I,
+    map: M,
+}
+
+impl<I: Iterator<Item = char>, M: Map<char, Option<char>>> Iterator for OptionReplacerIter<I, M> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for c in self.iter.by_ref() {
+            match self.map.get(&c) {
+                Some(Some(c)) => return Some(*c),
+                Some(None) => {}
+                None => return Some(c),
+            }
+        }
+
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // every character could be removed
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I, M> DoubleEndedIterator for OptionReplacerIter<I, M>
+where
+    I: DoubleEndedIterator<Item = char>,
+    M: Map<char, Option<char>>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while let Some(c) = self.iter.next_back() {
+            match self.map.get(&c) {
+                Some(Some(c)) => return Some(*c),
+                Some(None) => {}
+                None => return Some(c),
+            }
+        }
+
+        None
+    }
+}
+
+impl<I, M> FusedIterator for OptionReplacerIter<I, M>
+where
+    I: FusedIterator<Item = char>,
+    M: Map<char, Option<char>>,
+{
+}
+
+#[derive(Debug, Clone)]
+pub struct StrReplacerIter<'a, I: Iterator<Item = char>, M: Map<char, &'a str>> {
+    iter: I,
+    map: M,
+    /// the remaining characters of the current replacement
+    replacement: Chars<'a>,
+}
+
+impl<'a, I: Iterator<Item = char>, M: Map<char, &'a str>> Iterator for StrReplacerIter<'a, I, M> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(c) = self.replacement.next() {
+            return Some(c);
+        }
+
+        for c in self.iter.by_ref() {
+            if let Some(replacement) = self.map.get(&c) {
+                self.replacement = replacement.chars();
+                // an empty replacement removes the character
+                if let Some(c) = self.replacement.next() {
+                    return Some(c);
+                }
+            } else {
+                return Some(c);
+            }
+        }
+
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower_bound, upper_bound) = self.replacement.size_hint();
+
+        // the length of the replacements is unknown, unless there are no more characters
+        match self.iter.size_hint() {
+            (_, Some(0)) => (lower_bound, upper_bound),
+            _ => (lower_bound, None),
+        }
+    }
+}
+
+impl<'a, I, M> FusedIterator for StrReplacerIter<'a, I, M>
+where
+    I: FusedIterator<Item = char>,
+    M: Map<char, &'a str>,
+{
+}
+
+/// WSL's `DrvFs` and Cygwin store characters that are forbidden on Windows,
+/// by adding this offset to them, which moves them into the private use area.
+pub const PRIVATE_USE_OFFSET: u32 = 0xF000;
+
+#[must_use]
+fn to_private_use(c: char) -> char {
+    char::from_u32(PRIVATE_USE_OFFSET + u32::from(c))
+        .expect("the character can not be mapped into the private use area")
+}
+
+impl<const N: usize> Replacer<Sorted<[(char, char); N]>> {
+    /// Maps the `chars` to `U+F000 + c`, which can be reversed with [`Replacer::from_private_use`].
+    ///
+    /// # Panics
+    ///
+    /// If one of the `chars` is so large, that adding the offset would not result in a valid `char`.
+    #[must_use]
+    pub fn to_private_use(chars: [char; N]) -> Self {
+        Self::from(Sorted::new(chars.map(|c| (c, to_private_use(c)))))
+    }
+
+    /// The inverse of [`Replacer::to_private_use`], which maps `U+F000 + c` back to `c`.
+    ///
+    /// # Panics
+    ///
+    /// If one of the `chars` is so large, that adding the offset would not result in a valid `char`.
+    #[must_use]
+    pub fn from_private_use(chars: [char; N]) -> Self {
+        Self::from(Sorted::new(chars.map(|c| (to_private_use(c), c))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_replace_str() {
+        let replacer: Replacer<_, &str> = Replacer::from([('&', "and"), (':', " -"), ('?', "")]);
+        let iter = replacer.sanitize("Q&A: why?".chars());
+
+        assert_iter_eq_str!(iter, "QandA - why");
+    }
+
+    #[test]
+    fn test_replace_option() {
+        let replacer = Replacer::from([('?', None), ('*', Some('_'))]);
+        let iter = replacer.sanitize("?a*b?".chars());
+
+        assert_iter_eq_str!(iter, "a_b");
+    }
+
+    #[test]
+    fn test_size_hint() {
+        let replacer = Replacer::from([('?', '_')]);
+        let iter = replacer.sanitize(['a', '?', 'b'].into_iter());
+        assert_eq!(iter.len(), 3);
+        assert_iter_eq_str!(iter.rev(), "b_a");
+
+        let replacer = Replacer::from([('?', None), ('*', Some('_'))]);
+        let iter = replacer.sanitize(['a', '?', '*'].into_iter());
+        assert_eq!(iter.size_hint(), (0, Some(3)));
+        assert_iter_eq_str!(iter.rev(), "_a");
+
+        let replacer: Replacer<_, &str> = Replacer::from([('&', "and")]);
+        let mut iter = replacer.sanitize("a&".chars());
+        assert_eq!(iter.size_hint(), (0, None));
+        assert_eq!(iter.next(), Some('a'));
+        assert_eq!(iter.next(), Some('a'));
+        assert_eq!(iter.size_hint(), (1, Some(2)));
+    }
+
+    #[test]
+    fn test_private_use_roundtrip() {
+        let string = "a:b*c";
+        let encoded = Replacer::to_private_use([':', '*']).sanitize(string.chars());
+        let mut iter = Replacer::from_private_use([':', '*']).sanitize(encoded);
+
+        assert_eq!(iter.next(), Some('a'));
+        assert_eq!(iter.next(), Some(':'));
+        assert_eq!(iter.next(), Some('b'));
+        assert_eq!(iter.next(), Some('*'));
+        assert_eq!(iter.next(), Some('c'));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_to_private_use() {
+        let mut iter = Replacer::to_private_use([':', '\x01']).sanitize(":\x01?".chars());
+
+        assert_eq!(iter.next(), Some('\u{F03A}'));
+        assert_eq!(iter.next(), Some('\u{F001}'));
+        assert_eq!(iter.next(), Some('?'));
+        assert_eq!(iter.next(), None);
+    }
 }
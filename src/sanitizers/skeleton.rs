@@ -0,0 +1,125 @@
+use core::iter::FusedIterator;
+
+use unicode_normalization::{Decompositions, UnicodeNormalization};
+
+use crate::report::Reason;
+use crate::sanitizer::Sanitizer;
+use crate::utils::Buffer;
+
+/// Maps every character to its confusable prototype, which results in the skeleton
+/// defined by <https://www.unicode.org/reports/tr39/#Confusable_Detection>.
+///
+/// Two names are confusable if they have the same skeleton, for example the skeleton of
+/// `раypal` (with a Cyrillic `р` and `а`) is `paypal`.
+///
+/// A character, whose prototype contains a path separator or a character forbidden on Windows,
+/// like `∕` (`U+2215`) with the prototype `/`, is kept, so the skeleton of a sanitized name
+/// is still a valid name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Skeleton {}
+
+impl Sanitizer for Skeleton {
+    type Iter<I: Iterator<Item = char>> = Decompositions<PrototypeIter<Decompositions<I>>>;
+
+    fn sanitize<I: Iterator<Item = char>>(self, iter: I) -> Self::Iter<I> {
+        PrototypeIter {
+            iter: iter.nfd(),
+            buffer: Buffer::new(),
+        }
+        .nfd()
+    }
+
+    fn reason(&self) -> Reason {
+        Reason::Confusable
+    }
+}
+
+/// Returns true for the characters, which a prototype must not introduce into a name.
+fn is_forbidden(c: char) -> bool {
+    matches!(c, '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|') || c.is_control()
+}
+
+#[derive(Debug, Clone)]
+pub struct PrototypeIter<I: Iterator<Item = char>> {
+    iter: I,
+    /// the longest prototype consists of 18 characters
+    buffer: Buffer<18>,
+}
+
+impl<I: Iterator<Item = char>> Iterator for PrototypeIter<I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(c) = self.buffer.next() {
+            return Some(c);
+        }
+
+        let c = self.iter.next()?;
+        let mut bytes = [0; 4];
+        for c in unicode_security::skeleton(c.encode_utf8(&mut bytes)) {
+            self.buffer.push(c);
+        }
+
+        if self.buffer.as_slice().iter().any(|c| is_forbidden(*c)) {
+            self.buffer.clear();
+            return Some(c);
+        }
+
+        self.buffer.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower_bound, upper_bound) = self.iter.size_hint();
+        let buffered = self.buffer.len();
+
+        // every character has a prototype of one to 18 characters
+        (
+            lower_bound.saturating_add(buffered),
+            upper_bound
+                .and_then(|upper_bound| upper_bound.checked_mul(18))
+                .and_then(|upper_bound| upper_bound.checked_add(buffered)),
+        )
+    }
+}
+
+impl<I: FusedIterator<Item = char>> FusedIterator for PrototypeIter<I> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::platforms::Windows;
+    use crate::{Platform, SanitizerExt};
+
+    #[test]
+    fn test_skeleton() {
+        let iter = Skeleton::default().sanitize("\u{0440}\u{0430}ypal.pdf".chars());
+        assert_iter_eq_str!(iter, "paypal.pdf");
+
+        let iter = Skeleton::default().sanitize("paypal.pdf".chars());
+        assert_iter_eq_str!(iter, "paypal.pdf");
+    }
+
+    #[test]
+    fn test_skeleton_multiple() {
+        let iter = Skeleton::default().sanitize("m".chars());
+        assert_iter_eq_str!(iter, "rn");
+    }
+
+    #[test]
+    fn test_forbidden_prototype() {
+        let iter = Skeleton::default().sanitize("a\u{2215}b\u{A789}".chars());
+        assert_iter_eq_str!(iter, "a\u{2215}b\u{A789}");
+
+        let iter = Skeleton::default().sanitize("\u{2044}\u{FF1A}a/b".chars());
+        assert_iter_eq_str!(iter, "\u{2044}\u{FF1A}a/b");
+
+        // the skeleton of a sanitized name is still valid on the platform
+        let platform: Windows<'_', '_'> = Windows::default();
+        let iter = platform
+            .filename_sanitizer()
+            .skeleton()
+            .sanitize("a\u{2215}b\u{A789}".chars());
+        assert_iter_eq_str!(iter, "a\u{2215}b\u{A789}");
+    }
+}
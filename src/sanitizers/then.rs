@@ -1,3 +1,5 @@
+#[cfg(feature = "alloc")]
+use crate::report::Report;
 use crate::sanitizer::Sanitizer;
 
 /// Can be used to chain two sanitizers, first `A` is applied then `B`.
@@ -9,7 +11,7 @@ pub struct Then<A: Sanitizer, B: Sanitizer> {
 
 impl<A: Sanitizer, B: Sanitizer> Then<A, B> {
     #[must_use]
-    pub fn new(a: A, b: B) -> Self {
+    pub const fn new(a: A, b: B) -> Self {
         Self { a, b }
     }
 }
@@ -20,6 +22,14 @@ impl<A: Sanitizer, B: Sanitizer> Sanitizer for Then<A, B> {
     fn sanitize<I: Iterator<Item = char>>(self, iter: I) -> Self::Iter<I> {
         self.b.sanitize(self.a.sanitize(iter))
     }
+
+    /// Reports the changes of both sanitizers, see [`Report::then`].
+    #[cfg(feature = "alloc")]
+    fn report(self, input: &str) -> Report {
+        let report = self.a.report(input);
+        let next = self.b.report(report.output());
+        report.then(next)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,128 @@
+use core::iter::{FusedIterator, Peekable};
+use core::mem;
+
+use crate::report::Reason;
+use crate::sanitizer::Sanitizer;
+
+/// Replaces the last character with the `replacement`, if `is_trailing` returns true for it,
+/// and turns the empty name into the `replacement`.
+///
+/// Windows removes trailing dots and spaces, so `a.` would become `a` and `CON.` the reserved name `CON`,
+/// those become `a_` and `CON_` with `_` instead. The length of the name only changes, if the replacement
+/// is longer than the replaced character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TrailingReplacer<F> {
+    is_trailing: F,
+    replacement: char,
+}
+
+impl<F: FnMut(char) -> bool> TrailingReplacer<F> {
+    #[must_use]
+    pub const fn new(is_trailing: F, replacement: char) -> Self {
+        Self {
+            is_trailing,
+            replacement,
+        }
+    }
+}
+
+impl<F: FnMut(char) -> bool> Sanitizer for TrailingReplacer<F> {
+    type Iter<I: Iterator<Item = char>> = TrailingReplacerIter<I, F>;
+
+    fn sanitize<I: Iterator<Item = char>>(self, iter: I) -> Self::Iter<I> {
+        TrailingReplacerIter {
+            iter: iter.peekable(),
+            is_trailing: self.is_trailing,
+            replacement: self.replacement,
+            is_empty: true,
+        }
+    }
+
+    fn reason(&self) -> Reason {
+        Reason::TrailingCharacter
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TrailingReplacerIter<I: Iterator<Item = char>, F: FnMut(char) -> bool> {
+    iter: Peekable<I>,
+    is_trailing: F,
+    replacement: char,
+    /// true until the first character has been returned
+    is_empty: bool,
+}
+
+impl<I: Iterator<Item = char>, F: FnMut(char) -> bool> Iterator for TrailingReplacerIter<I, F> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Some(c) = self.iter.next() else {
+            return mem::take(&mut self.is_empty).then_some(self.replacement);
+        };
+        self.is_empty = false;
+
+        if self.iter.peek().is_none() && (self.is_trailing)(c) {
+            return Some(self.replacement);
+        }
+
+        Some(c)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower_bound, upper_bound) = self.iter.size_hint();
+        let empty = usize::from(self.is_empty);
+
+        (
+            lower_bound.max(empty),
+            upper_bound.map(|upper_bound| upper_bound.max(empty)),
+        )
+    }
+}
+
+impl<I, F> FusedIterator for TrailingReplacerIter<I, F>
+where
+    I: FusedIterator<Item = char>,
+    F: FnMut(char) -> bool,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn is_trailing(c: char) -> bool {
+        c == '.' || c == ' '
+    }
+
+    #[test]
+    fn test_trailing() {
+        let sanitizer = TrailingReplacer::new(is_trailing, '_');
+
+        assert_iter_eq_str!(sanitizer.sanitize("a.".chars()), "a_");
+        assert_iter_eq_str!(sanitizer.sanitize("a ".chars()), "a_");
+        assert_iter_eq_str!(sanitizer.sanitize("a. .".chars()), "a. _");
+        assert_iter_eq_str!(sanitizer.sanitize(".a".chars()), ".a");
+        assert_iter_eq_str!(sanitizer.sanitize("a".chars()), "a");
+    }
+
+    #[test]
+    fn test_empty() {
+        let sanitizer = TrailingReplacer::new(is_trailing, '_');
+
+        assert_iter_eq_str!(sanitizer.sanitize("".chars()), "_");
+        assert_iter_eq_str!(sanitizer.sanitize(".".chars()), "_");
+    }
+
+    #[test]
+    fn test_size_hint() {
+        let sanitizer = TrailingReplacer::new(is_trailing, '_');
+        let mut iter = sanitizer.sanitize("".chars());
+
+        assert_eq!(iter.size_hint(), (1, Some(1)));
+        assert_eq!(iter.next(), Some('_'));
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+    }
+}
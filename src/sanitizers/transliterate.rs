@@ -0,0 +1,597 @@
+use crate::report::Reason;
+use crate::sanitizer::Sanitizer;
+use crate::sanitizers::{Replacer, StrReplacerIter};
+use crate::utils::{Map, Sorted};
+
+/// Transliterates non-ASCII characters to ASCII, for example `Müller Straße` becomes `Mueller Strasse`.
+///
+/// Latin, Greek and Cyrillic letters are transliterated with built-in tables, combining marks
+/// are removed and every other non-ASCII character is replaced with the `fallback`,
+/// an empty fallback removes them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Transliterate<'a> {
+    fallback: &'a str,
+}
+
+impl<'a> Transliterate<'a> {
+    #[must_use]
+    pub const fn new(fallback: &'a str) -> Self {
+        Self { fallback }
+    }
+}
+
+#[must_use]
+const fn is_combining_mark(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0300}'..='\u{036F}'
+            | '\u{1AB0}'..='\u{1AFF}'
+            | '\u{1DC0}'..='\u{1DFF}'
+            | '\u{20D0}'..='\u{20FF}'
+            | '\u{FE20}'..='\u{FE2F}'
+    )
+}
+
+impl<'a> Map<char, &'a str> for Transliterate<'a> {
+    fn get(&self, key: &char) -> Option<&&'a str> {
+        if key.is_ascii() {
+            None
+        } else if is_combining_mark(*key) {
+            Some(&"")
+        } else {
+            Some(TABLE.get(key).unwrap_or(&self.fallback))
+        }
+    }
+}
+
+impl<'a> Sanitizer for Transliterate<'a> {
+    type Iter<I: Iterator<Item = char>> = StrReplacerIter<'a, I, Self>;
+
+    fn sanitize<I: Iterator<Item = char>>(self, iter: I) -> Self::Iter<I> {
+        Replacer::from(self).sanitize(iter)
+    }
+
+    fn reason(&self) -> Reason {
+        Reason::Transliterated
+    }
+}
+
+static TABLE: Sorted<&[(char, &str)]> = Sorted::from_sorted(PAIRS);
+
+/// The transliterations of Latin, Greek and Cyrillic letters, sorted by the character.
+#[rustfmt::skip]
+const PAIRS: &[(char, &str)] = &[
+    ('\u{00A0}', " "),
+    ('¡', "!"),
+    ('¢', "c"),
+    ('£', "L"),
+    ('¤', ""),
+    ('¥', "Y"),
+    ('¦', "-"),
+    ('§', "S"),
+    ('¨', ""),
+    ('©', "(c)"),
+    ('ª', "a"),
+    ('«', "-"),
+    ('¬', "-"),
+    ('\u{00AD}', ""),
+    ('®', "(r)"),
+    ('¯', "-"),
+    ('°', "o"),
+    ('±', "+-"),
+    ('²', "2"),
+    ('³', "3"),
+    ('´', "'"),
+    ('µ', "u"),
+    ('¶', "P"),
+    ('·', "."),
+    ('¸', ","),
+    ('¹', "1"),
+    ('º', "o"),
+    ('»', "-"),
+    ('¼', "1-4"),
+    ('½', "1-2"),
+    ('¾', "3-4"),
+    ('¿', ""),
+    ('À', "A"),
+    ('Á', "A"),
+    ('Â', "A"),
+    ('Ã', "A"),
+    ('Ä', "Ae"),
+    ('Å', "Aa"),
+    ('Æ', "AE"),
+    ('Ç', "C"),
+    ('È', "E"),
+    ('É', "E"),
+    ('Ê', "E"),
+    ('Ë', "E"),
+    ('Ì', "I"),
+    ('Í', "I"),
+    ('Î', "I"),
+    ('Ï', "I"),
+    ('Ð', "D"),
+    ('Ñ', "N"),
+    ('Ò', "O"),
+    ('Ó', "O"),
+    ('Ô', "O"),
+    ('Õ', "O"),
+    ('Ö', "Oe"),
+    ('×', "x"),
+    ('Ø', "Oe"),
+    ('Ù', "U"),
+    ('Ú', "U"),
+    ('Û', "U"),
+    ('Ü', "Ue"),
+    ('Ý', "Y"),
+    ('Þ', "Th"),
+    ('ß', "ss"),
+    ('à', "a"),
+    ('á', "a"),
+    ('â', "a"),
+    ('ã', "a"),
+    ('ä', "ae"),
+    ('å', "aa"),
+    ('æ', "ae"),
+    ('ç', "c"),
+    ('è', "e"),
+    ('é', "e"),
+    ('ê', "e"),
+    ('ë', "e"),
+    ('ì', "i"),
+    ('í', "i"),
+    ('î', "i"),
+    ('ï', "i"),
+    ('ð', "d"),
+    ('ñ', "n"),
+    ('ò', "o"),
+    ('ó', "o"),
+    ('ô', "o"),
+    ('õ', "o"),
+    ('ö', "oe"),
+    ('÷', "-"),
+    ('ø', "oe"),
+    ('ù', "u"),
+    ('ú', "u"),
+    ('û', "u"),
+    ('ü', "ue"),
+    ('ý', "y"),
+    ('þ', "th"),
+    ('ÿ', "y"),
+    ('Ā', "A"),
+    ('ā', "a"),
+    ('Ă', "A"),
+    ('ă', "a"),
+    ('Ą', "A"),
+    ('ą', "a"),
+    ('Ć', "C"),
+    ('ć', "c"),
+    ('Ĉ', "C"),
+    ('ĉ', "c"),
+    ('Ċ', "C"),
+    ('ċ', "c"),
+    ('Č', "C"),
+    ('č', "c"),
+    ('Ď', "D"),
+    ('ď', "d"),
+    ('Đ', "D"),
+    ('đ', "d"),
+    ('Ē', "E"),
+    ('ē', "e"),
+    ('Ĕ', "E"),
+    ('ĕ', "e"),
+    ('Ė', "E"),
+    ('ė', "e"),
+    ('Ę', "E"),
+    ('ę', "e"),
+    ('Ě', "E"),
+    ('ě', "e"),
+    ('Ĝ', "G"),
+    ('ĝ', "g"),
+    ('Ğ', "G"),
+    ('ğ', "g"),
+    ('Ġ', "G"),
+    ('ġ', "g"),
+    ('Ģ', "G"),
+    ('ģ', "g"),
+    ('Ĥ', "H"),
+    ('ĥ', "h"),
+    ('Ħ', "H"),
+    ('ħ', "h"),
+    ('Ĩ', "I"),
+    ('ĩ', "i"),
+    ('Ī', "I"),
+    ('ī', "i"),
+    ('Ĭ', "I"),
+    ('ĭ', "i"),
+    ('Į', "I"),
+    ('į', "i"),
+    ('İ', "I"),
+    ('ı', "i"),
+    ('Ĳ', "IJ"),
+    ('ĳ', "ij"),
+    ('Ĵ', "J"),
+    ('ĵ', "j"),
+    ('Ķ', "K"),
+    ('ķ', "k"),
+    ('ĸ', "q"),
+    ('Ĺ', "L"),
+    ('ĺ', "l"),
+    ('Ļ', "L"),
+    ('ļ', "l"),
+    ('Ľ', "L"),
+    ('ľ', "l"),
+    ('Ŀ', "L"),
+    ('ŀ', "l"),
+    ('Ł', "L"),
+    ('ł', "l"),
+    ('Ń', "N"),
+    ('ń', "n"),
+    ('Ņ', "N"),
+    ('ņ', "n"),
+    ('Ň', "N"),
+    ('ň', "n"),
+    ('ŉ', "'n"),
+    ('Ŋ', "N"),
+    ('ŋ', "n"),
+    ('Ō', "O"),
+    ('ō', "o"),
+    ('Ŏ', "O"),
+    ('ŏ', "o"),
+    ('Ő', "O"),
+    ('ő', "o"),
+    ('Œ', "OE"),
+    ('œ', "oe"),
+    ('Ŕ', "R"),
+    ('ŕ', "r"),
+    ('Ŗ', "R"),
+    ('ŗ', "r"),
+    ('Ř', "R"),
+    ('ř', "r"),
+    ('Ś', "S"),
+    ('ś', "s"),
+    ('Ŝ', "S"),
+    ('ŝ', "s"),
+    ('Ş', "S"),
+    ('ş', "s"),
+    ('Š', "S"),
+    ('š', "s"),
+    ('Ţ', "T"),
+    ('ţ', "t"),
+    ('Ť', "T"),
+    ('ť', "t"),
+    ('Ŧ', "T"),
+    ('ŧ', "t"),
+    ('Ũ', "U"),
+    ('ũ', "u"),
+    ('Ū', "U"),
+    ('ū', "u"),
+    ('Ŭ', "U"),
+    ('ŭ', "u"),
+    ('Ů', "U"),
+    ('ů', "u"),
+    ('Ű', "U"),
+    ('ű', "u"),
+    ('Ų', "U"),
+    ('ų', "u"),
+    ('Ŵ', "W"),
+    ('ŵ', "w"),
+    ('Ŷ', "Y"),
+    ('ŷ', "y"),
+    ('Ÿ', "Y"),
+    ('Ź', "Z"),
+    ('ź', "z"),
+    ('Ż', "Z"),
+    ('ż', "z"),
+    ('Ž', "Z"),
+    ('ž', "z"),
+    ('ſ', "s"),
+    ('Ə', "E"),
+    ('ƒ', "f"),
+    ('Ơ', "O"),
+    ('ơ', "o"),
+    ('Ư', "U"),
+    ('ư', "u"),
+    ('Ǎ', "A"),
+    ('ǎ', "a"),
+    ('Ǐ', "I"),
+    ('ǐ', "i"),
+    ('Ǒ', "O"),
+    ('ǒ', "o"),
+    ('Ǔ', "U"),
+    ('ǔ', "u"),
+    ('Ǖ', "U"),
+    ('ǖ', "u"),
+    ('Ǘ', "U"),
+    ('ǘ', "u"),
+    ('Ǚ', "U"),
+    ('ǚ', "u"),
+    ('Ǜ', "U"),
+    ('ǜ', "u"),
+    ('Ǟ', "A"),
+    ('ǟ', "a"),
+    ('Ǡ', "A"),
+    ('ǡ', "a"),
+    ('Ǧ', "G"),
+    ('ǧ', "g"),
+    ('Ǩ', "K"),
+    ('ǩ', "k"),
+    ('Ǫ', "O"),
+    ('ǫ', "o"),
+    ('Ǭ', "O"),
+    ('ǭ', "o"),
+    ('ǰ', "j"),
+    ('Ǵ', "G"),
+    ('ǵ', "g"),
+    ('Ǹ', "N"),
+    ('ǹ', "n"),
+    ('Ǻ', "A"),
+    ('ǻ', "a"),
+    ('Ȁ', "A"),
+    ('ȁ', "a"),
+    ('Ȃ', "A"),
+    ('ȃ', "a"),
+    ('Ȅ', "E"),
+    ('ȅ', "e"),
+    ('Ȇ', "E"),
+    ('ȇ', "e"),
+    ('Ȉ', "I"),
+    ('ȉ', "i"),
+    ('Ȋ', "I"),
+    ('ȋ', "i"),
+    ('Ȍ', "O"),
+    ('ȍ', "o"),
+    ('Ȏ', "O"),
+    ('ȏ', "o"),
+    ('Ȑ', "R"),
+    ('ȑ', "r"),
+    ('Ȓ', "R"),
+    ('ȓ', "r"),
+    ('Ȕ', "U"),
+    ('ȕ', "u"),
+    ('Ȗ', "U"),
+    ('ȗ', "u"),
+    ('Ș', "S"),
+    ('ș', "s"),
+    ('Ț', "T"),
+    ('ț', "t"),
+    ('Ȟ', "H"),
+    ('ȟ', "h"),
+    ('Ȧ', "A"),
+    ('ȧ', "a"),
+    ('Ȩ', "E"),
+    ('ȩ', "e"),
+    ('Ȫ', "O"),
+    ('ȫ', "o"),
+    ('Ȭ', "O"),
+    ('ȭ', "o"),
+    ('Ȯ', "O"),
+    ('ȯ', "o"),
+    ('Ȱ', "O"),
+    ('ȱ', "o"),
+    ('Ȳ', "Y"),
+    ('ȳ', "y"),
+    ('ə', "e"),
+    ('΅', ""),
+    ('Ά', "A"),
+    ('\u{0387}', "."),
+    ('Έ', "E"),
+    ('Ή', "I"),
+    ('Ί', "I"),
+    ('Ό', "O"),
+    ('Ύ', "Y"),
+    ('Ώ', "O"),
+    ('ΐ', "i"),
+    ('Α', "A"),
+    ('Β', "V"),
+    ('Γ', "G"),
+    ('Δ', "D"),
+    ('Ε', "E"),
+    ('Ζ', "Z"),
+    ('Η', "I"),
+    ('Θ', "Th"),
+    ('Ι', "I"),
+    ('Κ', "K"),
+    ('Λ', "L"),
+    ('Μ', "M"),
+    ('Ν', "N"),
+    ('Ξ', "X"),
+    ('Ο', "O"),
+    ('Π', "P"),
+    ('Ρ', "R"),
+    ('Σ', "S"),
+    ('Τ', "T"),
+    ('Υ', "Y"),
+    ('Φ', "F"),
+    ('Χ', "Ch"),
+    ('Ψ', "Ps"),
+    ('Ω', "O"),
+    ('Ϊ', "I"),
+    ('Ϋ', "Y"),
+    ('ά', "a"),
+    ('έ', "e"),
+    ('ή', "i"),
+    ('ί', "i"),
+    ('ΰ', "y"),
+    ('α', "a"),
+    ('β', "v"),
+    ('γ', "g"),
+    ('δ', "d"),
+    ('ε', "e"),
+    ('ζ', "z"),
+    ('η', "i"),
+    ('θ', "th"),
+    ('ι', "i"),
+    ('κ', "k"),
+    ('λ', "l"),
+    ('μ', "m"),
+    ('ν', "n"),
+    ('ξ', "x"),
+    ('ο', "o"),
+    ('π', "p"),
+    ('ρ', "r"),
+    ('ς', "s"),
+    ('σ', "s"),
+    ('τ', "t"),
+    ('υ', "y"),
+    ('φ', "f"),
+    ('χ', "ch"),
+    ('ψ', "ps"),
+    ('ω', "o"),
+    ('ϊ', "i"),
+    ('ϋ', "y"),
+    ('ό', "o"),
+    ('ύ', "y"),
+    ('ώ', "o"),
+    ('Ё', "Yo"),
+    ('Ђ', "Dj"),
+    ('Ѓ', "Gj"),
+    ('Є', "Ye"),
+    ('Ѕ', "Dz"),
+    ('І', "I"),
+    ('Ї', "Yi"),
+    ('Ј', "J"),
+    ('Љ', "Lj"),
+    ('Њ', "Nj"),
+    ('Ћ', "C"),
+    ('Ќ', "Kj"),
+    ('Ў', "U"),
+    ('Џ', "Dz"),
+    ('А', "A"),
+    ('Б', "B"),
+    ('В', "V"),
+    ('Г', "G"),
+    ('Д', "D"),
+    ('Е', "E"),
+    ('Ж', "Zh"),
+    ('З', "Z"),
+    ('И', "I"),
+    ('Й', "Y"),
+    ('К', "K"),
+    ('Л', "L"),
+    ('М', "M"),
+    ('Н', "N"),
+    ('О', "O"),
+    ('П', "P"),
+    ('Р', "R"),
+    ('С', "S"),
+    ('Т', "T"),
+    ('У', "U"),
+    ('Ф', "F"),
+    ('Х', "Kh"),
+    ('Ц', "Ts"),
+    ('Ч', "Ch"),
+    ('Ш', "Sh"),
+    ('Щ', "Shch"),
+    ('Ъ', ""),
+    ('Ы', "Y"),
+    ('Ь', ""),
+    ('Э', "E"),
+    ('Ю', "Yu"),
+    ('Я', "Ya"),
+    ('а', "a"),
+    ('б', "b"),
+    ('в', "v"),
+    ('г', "g"),
+    ('д', "d"),
+    ('е', "e"),
+    ('ж', "zh"),
+    ('з', "z"),
+    ('и', "i"),
+    ('й', "y"),
+    ('к', "k"),
+    ('л', "l"),
+    ('м', "m"),
+    ('н', "n"),
+    ('о', "o"),
+    ('п', "p"),
+    ('р', "r"),
+    ('с', "s"),
+    ('т', "t"),
+    ('у', "u"),
+    ('ф', "f"),
+    ('х', "kh"),
+    ('ц', "ts"),
+    ('ч', "ch"),
+    ('ш', "sh"),
+    ('щ', "shch"),
+    ('ъ', ""),
+    ('ы', "y"),
+    ('ь', ""),
+    ('э', "e"),
+    ('ю', "yu"),
+    ('я', "ya"),
+    ('ё', "yo"),
+    ('ђ', "dj"),
+    ('ѓ', "gj"),
+    ('є', "ye"),
+    ('ѕ', "dz"),
+    ('і', "i"),
+    ('ї', "yi"),
+    ('ј', "j"),
+    ('љ', "lj"),
+    ('њ', "nj"),
+    ('ћ', "c"),
+    ('ќ', "kj"),
+    ('ў', "u"),
+    ('џ', "dz"),
+    ('Ґ', "G"),
+    ('ґ', "g"),
+    ('ẞ', "SS"),
+    ('€', "EUR"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_latin() {
+        let iter = Transliterate::new("_").sanitize("Müller Straße Ærø.pdf".chars());
+        assert_iter_eq_str!(iter, "Mueller Strasse AEroe.pdf");
+
+        let iter = Transliterate::new("_").sanitize("Crème brûlée, Łódź".chars());
+        assert_iter_eq_str!(iter, "Creme brulee, Lodz");
+    }
+
+    #[test]
+    fn test_combining_marks() {
+        let iter = Transliterate::new("_").sanitize("cafe\u{0301}".chars());
+        assert_iter_eq_str!(iter, "cafe");
+    }
+
+    #[test]
+    fn test_greek() {
+        let iter = Transliterate::new("_").sanitize("Αθήνα Ψυχή".chars());
+        assert_iter_eq_str!(iter, "Athina Psychi");
+    }
+
+    #[test]
+    fn test_cyrillic() {
+        let iter = Transliterate::new("_").sanitize("Щука и Жёлудь".chars());
+        assert_iter_eq_str!(iter, "Shchuka i Zhyolud");
+    }
+
+    #[test]
+    fn test_fallback() {
+        let iter = Transliterate::new("_").sanitize("東京.txt".chars());
+        assert_iter_eq_str!(iter, "__.txt");
+
+        let iter = Transliterate::new("").sanitize("東京.txt".chars());
+        assert_iter_eq_str!(iter, ".txt");
+    }
+
+    #[test]
+    fn test_table() {
+        assert!(PAIRS.windows(2).all(|w| w[0].0 < w[1].0));
+        assert!(PAIRS.iter().all(|(c, s)| !c.is_ascii() && s.is_ascii()));
+
+        // the transliteration must not undo a platform sanitizer
+        for (c, s) in PAIRS {
+            assert!(
+                !s.contains(['/', '\\', ':', '*', '?', '"', '<', '>', '|'])
+                    && !s.contains(|c: char| c.is_control()),
+                "{c:?} is transliterated to {s:?}"
+            );
+        }
+    }
+}
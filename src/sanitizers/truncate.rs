@@ -0,0 +1,141 @@
+use core::iter::FusedIterator;
+
+use crate::encoding;
+use crate::report::Reason;
+use crate::sanitizer::Sanitizer;
+
+/// Truncates the name to at most `max_len` bytes, when it is encoded as UTF-8,
+/// or `max_len` code units, when it is encoded as UTF-16 like on Windows.
+///
+/// The unpaired surrogates of [`DecodeMode::PassThrough`](crate::encoding::DecodeMode::PassThrough)
+/// count as a single code unit.
+///
+/// The name is only cut between two characters, but combining characters like accents
+/// may be separated from their base character, see [`GraphemeTruncate`](crate::sanitizers::GraphemeTruncate) for an alternative.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Truncate {
+    max_len: usize,
+    is_utf16: bool,
+}
+
+impl Truncate {
+    /// Creates a sanitizer, which limits the length in UTF-8 bytes.
+    #[must_use]
+    pub const fn new(max_len: usize) -> Self {
+        Self {
+            max_len,
+            is_utf16: false,
+        }
+    }
+
+    /// Creates a sanitizer, which limits the length in UTF-16 code units.
+    #[must_use]
+    pub const fn utf16(max_len: usize) -> Self {
+        Self {
+            max_len,
+            is_utf16: true,
+        }
+    }
+}
+
+impl Sanitizer for Truncate {
+    type Iter<I: Iterator<Item = char>> = TruncateIter<I>;
+
+    fn sanitize<I: Iterator<Item = char>>(self, iter: I) -> Self::Iter<I> {
+        TruncateIter {
+            iter,
+            remaining: self.max_len,
+            is_utf16: self.is_utf16,
+        }
+    }
+
+    fn reason(&self) -> Reason {
+        Reason::Truncated
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TruncateIter<I: Iterator<Item = char>> {
+    iter: I,
+    /// the number of bytes or code units, that can still be emitted
+    remaining: usize,
+    is_utf16: bool,
+}
+
+impl<I: Iterator<Item = char>> Iterator for TruncateIter<I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let c = self.iter.next()?;
+        let len = if self.is_utf16 {
+            encoding::len_utf16(c)
+        } else {
+            c.len_utf8()
+        };
+
+        if len > self.remaining {
+            // nothing fits anymore, once a character has been cut off
+            self.remaining = 0;
+            return None;
+        }
+
+        self.remaining -= len;
+        Some(c)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // every character needs at least one byte or code unit
+        let upper_bound = self
+            .iter
+            .size_hint()
+            .1
+            .map_or(self.remaining, |upper_bound| {
+                upper_bound.min(self.remaining)
+            });
+
+        (0, Some(upper_bound))
+    }
+}
+
+impl<I: FusedIterator<Item = char>> FusedIterator for TruncateIter<I> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_truncate() {
+        assert_iter_eq_str!(Truncate::new(4).sanitize("abcdef".chars()), "abcd");
+        assert_iter_eq_str!(Truncate::new(4).sanitize("abc".chars()), "abc");
+        assert_iter_eq_str!(
+            Truncate::new(4).sanitize("ab\u{00E4}\u{00E4}".chars()),
+            "ab\u{00E4}"
+        );
+        assert_iter_eq_str!(Truncate::new(0).sanitize("abc".chars()), "");
+    }
+
+    #[test]
+    fn test_truncate_utf16() {
+        let iter = Truncate::utf16(4).sanitize("\u{00E4}\u{00E4}\u{00E4}\u{00E4}\u{00E4}".chars());
+        assert_iter_eq_str!(iter, "\u{00E4}\u{00E4}\u{00E4}\u{00E4}");
+
+        let iter = Truncate::utf16(3).sanitize("a\u{1F604}\u{1F604}".chars());
+        assert_iter_eq_str!(iter, "a\u{1F604}");
+
+        // the mapped unpaired surrogates are encoded as a single code unit
+        let iter = Truncate::utf16(3).sanitize("a\u{10D800}\u{10DFFF}\u{10E000}".chars());
+        assert_iter_eq_str!(iter, "a\u{10D800}\u{10DFFF}");
+    }
+
+    #[test]
+    fn test_size_hint() {
+        let iter = Truncate::new(4).sanitize(['a'; 8].into_iter());
+        assert_eq!(iter.size_hint(), (0, Some(4)));
+
+        let iter = Truncate::new(4).sanitize(['a'; 2].into_iter());
+        assert_eq!(iter.size_hint(), (0, Some(2)));
+    }
+}
@@ -1,8 +1,11 @@
 use core::iter;
 
+use crate::report::Reason;
 use crate::sanitizer::Sanitizer;
+use crate::sanitizers::replacement;
 
 /// This sanitizer replaces whitespace characters like `\t` or `\n` with `RP`
+/// or removes them if `RP` is [`REMOVE`](crate::sanitizers::REMOVE).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Whitespace<const RP: char> {}
 
@@ -13,10 +16,20 @@ impl<const RP: char> Default for Whitespace<RP> {
 }
 
 impl<const RP: char> Sanitizer for Whitespace<RP> {
-    type Iter<I: Iterator<Item = char>> = iter::Map<I, fn(char) -> char>;
+    type Iter<I: Iterator<Item = char>> = iter::FilterMap<I, fn(char) -> Option<char>>;
 
     fn sanitize<I: Iterator<Item = char>>(self, iter: I) -> Self::Iter<I> {
-        iter.map(|c| if c.is_whitespace() { RP } else { c })
+        iter.filter_map(|c| {
+            if c.is_whitespace() {
+                replacement::<RP>()
+            } else {
+                Some(c)
+            }
+        })
+    }
+
+    fn reason(&self) -> Reason {
+        Reason::Whitespace
     }
 }
 
@@ -0,0 +1,130 @@
+use super::Map;
+
+/// A set of ASCII characters, which is stored as a bitset, so lookups are a single bit test.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct AsciiSet(u128);
+
+impl AsciiSet {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    /// Creates a set, which contains all the `chars`.
+    ///
+    /// # Panics
+    ///
+    /// If one of the `chars` is not ASCII.
+    #[must_use]
+    pub const fn from_chars(chars: &[char]) -> Self {
+        let mut set = Self::new();
+        let mut i = 0;
+
+        while i < chars.len() {
+            set = set.with(chars[i]);
+            i += 1;
+        }
+
+        set
+    }
+
+    /// Returns a copy of the set, which also contains `c`.
+    ///
+    /// # Panics
+    ///
+    /// If `c` is not ASCII.
+    #[must_use]
+    pub const fn with(self, c: char) -> Self {
+        assert!(c.is_ascii(), "the character is not ASCII");
+
+        Self(self.0 | (1 << c as u32))
+    }
+
+    #[must_use]
+    pub const fn contains(&self, c: char) -> bool {
+        c.is_ascii() && self.0 & (1 << c as u32) != 0
+    }
+}
+
+/// Maps every character in the set to the same value.
+impl<V> Map<char, V> for (AsciiSet, V) {
+    fn get(&self, key: &char) -> Option<&V> {
+        let (set, v) = self;
+        set.contains(*key).then_some(v)
+    }
+}
+
+/// A lookup table for ASCII characters, where every character is mapped to an optional value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AsciiMap<V>([Option<V>; 128]);
+
+impl<V: Copy> AsciiMap<V> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self([None; 128])
+    }
+
+    /// Creates a table from the `(key, value)` pairs.
+    ///
+    /// # Panics
+    ///
+    /// If one of the keys is not ASCII.
+    #[must_use]
+    pub const fn from_pairs(pairs: &[(char, V)]) -> Self {
+        let mut map = Self::new();
+        let mut i = 0;
+
+        while i < pairs.len() {
+            let (c, v) = pairs[i];
+            assert!(c.is_ascii(), "the character is not ASCII");
+
+            map.0[c as usize] = Some(v);
+            i += 1;
+        }
+
+        map
+    }
+}
+
+impl<V: Copy> Default for AsciiMap<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V> Map<char, V> for AsciiMap<V> {
+    fn get(&self, key: &char) -> Option<&V> {
+        self.0.get(*key as usize)?.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_set() {
+        const SET: AsciiSet = AsciiSet::from_chars(&['\0', '/', '\x7F']);
+
+        assert!(SET.contains('\0'));
+        assert!(SET.contains('/'));
+        assert!(SET.contains('\x7F'));
+        assert!(!SET.contains('a'));
+        assert!(!SET.contains('\u{FF0F}'));
+
+        assert_eq!((SET, '_').get(&'/'), Some(&'_'));
+        assert_eq!((SET, '_').get(&'a'), None);
+    }
+
+    #[test]
+    fn test_map() {
+        const MAP: AsciiMap<&str> = AsciiMap::from_pairs(&[('&', "and"), ('\x7F', "")]);
+
+        assert_eq!(MAP.get(&'&'), Some(&"and"));
+        assert_eq!(MAP.get(&'\x7F'), Some(&""));
+        assert_eq!(MAP.get(&'a'), None);
+        assert_eq!(MAP.get(&'\u{0100}'), None);
+    }
+}
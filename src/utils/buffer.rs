@@ -0,0 +1,77 @@
+/// A fixed size queue of characters, for sanitizers that emit more than one character per input.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Buffer<const N: usize> {
+    chars: [char; N],
+    start: usize,
+    end: usize,
+}
+
+impl<const N: usize> Buffer<N> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            chars: ['\0'; N],
+            start: 0,
+            end: 0,
+        }
+    }
+
+    /// Appends the character to the end of the queue.
+    ///
+    /// # Panics
+    ///
+    /// If the buffer is full.
+    pub fn push(&mut self, c: char) {
+        if self.is_empty() {
+            self.clear();
+        }
+
+        self.chars[self.end] = c;
+        self.end += 1;
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns the characters, that are in the queue.
+    #[must_use]
+    pub fn as_slice(&self) -> &[char] {
+        &self.chars[self.start..self.end]
+    }
+
+    pub fn clear(&mut self) {
+        self.start = 0;
+        self.end = 0;
+    }
+}
+
+impl<const N: usize> Default for Buffer<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Iterator for Buffer<N> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_empty() {
+            return None;
+        }
+
+        self.start += 1;
+        Some(self.chars[self.start - 1])
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len(), Some(self.len()))
+    }
+}
@@ -0,0 +1,192 @@
+use core::iter::Peekable;
+
+use unicode_segmentation::GraphemeCursor;
+
+/// The maximum number of UTF-8 bytes of a grapheme cluster, longer clusters are split.
+pub const MAX_CLUSTER_LENGTH: usize = 64;
+
+/// A fixed size queue, which stores a grapheme cluster as UTF-8.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Cluster {
+    bytes: [u8; MAX_CLUSTER_LENGTH],
+    start: usize,
+    end: usize,
+}
+
+impl Cluster {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            bytes: [0; MAX_CLUSTER_LENGTH],
+            start: 0,
+            end: 0,
+        }
+    }
+
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.bytes[self.start..self.end]).unwrap_or_default()
+    }
+
+    /// Returns the number of UTF-8 bytes in the queue.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    pub fn clear(&mut self) {
+        self.start = 0;
+        self.end = 0;
+    }
+
+    /// Appends the character to the end of the queue.
+    ///
+    /// # Panics
+    ///
+    /// If the character does not fit into the queue.
+    pub fn push(&mut self, c: char) {
+        if self.is_empty() {
+            self.clear();
+        }
+
+        self.end += c.encode_utf8(&mut self.bytes[self.end..]).len();
+    }
+
+    /// Returns true if `c` starts a new grapheme cluster, when it is appended to the queue.
+    ///
+    /// The start of the queue is treated as the start of the text, which is correct,
+    /// as long as the queue starts at a cluster boundary.
+    #[must_use]
+    fn is_boundary(&self, c: char) -> bool {
+        if self.is_empty() || self.end + c.len_utf8() > MAX_CLUSTER_LENGTH {
+            return true;
+        }
+
+        let mut bytes = self.bytes;
+        let end = self.end + c.encode_utf8(&mut bytes[self.end..]).len();
+        let chunk = core::str::from_utf8(&bytes[self.start..end]).unwrap_or_default();
+
+        GraphemeCursor::new(self.len(), chunk.len(), true)
+            .is_boundary(chunk, 0)
+            .unwrap_or(true)
+    }
+}
+
+impl Default for Cluster {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Iterator for Cluster {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let c = self.as_str().chars().next()?;
+        self.start += c.len_utf8();
+
+        Some(c)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // a character has between 1 and 4 bytes
+        (self.len().div_ceil(4), Some(self.len()))
+    }
+}
+
+/// Splits the characters into extended grapheme clusters,
+/// see <https://www.unicode.org/reports/tr29/>.
+#[derive(Debug, Clone)]
+pub struct Segmenter<I: Iterator<Item = char>> {
+    iter: Peekable<I>,
+}
+
+impl<I: Iterator<Item = char>> Segmenter<I> {
+    #[must_use]
+    pub fn new(iter: I) -> Self {
+        Self {
+            iter: iter.peekable(),
+        }
+    }
+
+    /// Replaces the content of `cluster` with the next grapheme cluster,
+    /// returns false if there are no more characters.
+    pub fn next_cluster(&mut self, cluster: &mut Cluster) -> bool {
+        cluster.clear();
+
+        while let Some(c) = self
+            .iter
+            .next_if(|c| cluster.is_empty() || !cluster.is_boundary(*c))
+        {
+            cluster.push(c);
+        }
+
+        !cluster.is_empty()
+    }
+
+    /// The number of remaining characters, which is at least the number of remaining clusters.
+    #[must_use]
+    pub fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn clusters(string: &str) -> impl Iterator<Item = Cluster> + '_ {
+        let mut segmenter = Segmenter::new(string.chars());
+
+        core::iter::from_fn(move || {
+            let mut cluster = Cluster::new();
+            segmenter.next_cluster(&mut cluster).then_some(cluster)
+        })
+    }
+
+    #[test]
+    fn test_segmenter() {
+        let string =
+            "e\u{301}a\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{1F1E9}\u{1F1EA}\u{1F1EB}\r\n";
+        let mut iter = clusters(string);
+
+        assert_eq!(iter.next().as_ref().map(Cluster::as_str), Some("e\u{301}"));
+        assert_eq!(iter.next().as_ref().map(Cluster::as_str), Some("a"));
+        assert_eq!(
+            iter.next().as_ref().map(Cluster::as_str),
+            Some("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}")
+        );
+        assert_eq!(
+            iter.next().as_ref().map(Cluster::as_str),
+            Some("\u{1F1E9}\u{1F1EA}")
+        );
+        assert_eq!(iter.next().as_ref().map(Cluster::as_str), Some("\u{1F1EB}"));
+        assert_eq!(iter.next().as_ref().map(Cluster::as_str), Some("\r\n"));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_segmenter_long_cluster() {
+        // an `a` with 40 combining acute accents
+        let string = concat!(
+            "a",
+            "\u{301}\u{301}\u{301}\u{301}\u{301}\u{301}\u{301}\u{301}\u{301}\u{301}",
+            "\u{301}\u{301}\u{301}\u{301}\u{301}\u{301}\u{301}\u{301}\u{301}\u{301}",
+            "\u{301}\u{301}\u{301}\u{301}\u{301}\u{301}\u{301}\u{301}\u{301}\u{301}",
+            "\u{301}\u{301}\u{301}\u{301}\u{301}\u{301}\u{301}\u{301}\u{301}\u{301}",
+        );
+        let mut iter = clusters(string);
+
+        assert_eq!(iter.next().map(|cluster| cluster.len()), Some(63));
+        assert_eq!(iter.next().map(|cluster| cluster.len()), Some(18));
+        assert_eq!(iter.next(), None);
+    }
+}
@@ -6,7 +6,7 @@ pub trait Map<K, V> {
 impl<K: Eq, V> Map<K, V> for (K, V) {
     fn get(&self, key: &K) -> Option<&V> {
         let (k, v) = self;
-        key.eq(k).then(|| v)
+        key.eq(k).then_some(v)
     }
 }
 
@@ -19,8 +19,32 @@ where
     }
 }
 
+/// Maps every character, for which the function returns true, to the value.
+impl<V> Map<char, V> for (fn(char) -> bool, V) {
+    fn get(&self, key: &char) -> Option<&V> {
+        let (f, v) = self;
+        f(*key).then_some(v)
+    }
+}
+
 impl<K: Eq, V, const N: usize> Map<K, V> for [(K, V); N] {
     fn get(&self, key: &K) -> Option<&V> {
-        self.iter().find_map(|(k, v)| key.eq(k).then(|| v))
+        self.iter().find_map(|(k, v)| key.eq(k).then_some(v))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord, V> Map<K, V> for alloc::collections::BTreeMap<K, V> {
+    fn get(&self, key: &K) -> Option<&V> {
+        self.get(key)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + core::hash::Hash, V, S: core::hash::BuildHasher> Map<K, V>
+    for std::collections::HashMap<K, V, S>
+{
+    fn get(&self, key: &K) -> Option<&V> {
+        self.get(key)
     }
 }
@@ -1,3 +1,13 @@
+mod ascii;
+mod buffer;
+#[cfg(feature = "graphemes")]
+mod grapheme;
 mod map;
+mod sorted;
 
+pub use ascii::{AsciiMap, AsciiSet};
+pub use buffer::Buffer;
+#[cfg(feature = "graphemes")]
+pub use grapheme::{Cluster, Segmenter};
 pub use map::Map;
+pub use sorted::Sorted;
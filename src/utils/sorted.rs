@@ -0,0 +1,73 @@
+use super::Map;
+
+/// A map, which is backed by `(key, value)` pairs that are sorted by their key,
+/// so lookups can use a binary search instead of scanning all pairs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Sorted<T>(T);
+
+impl<T> Sorted<T> {
+    /// Sorts the pairs by their key.
+    #[must_use]
+    pub fn new<K: Ord, V>(mut pairs: T) -> Self
+    where
+        T: AsMut<[(K, V)]>,
+    {
+        pairs.as_mut().sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+        Self(pairs)
+    }
+
+    /// Wraps pairs, which are already sorted by their key (for example in a `const`).
+    ///
+    /// If the pairs are not sorted, lookups might not find the keys.
+    #[must_use]
+    pub const fn from_sorted(pairs: T) -> Self {
+        Self(pairs)
+    }
+}
+
+#[must_use]
+fn binary_search<'a, K: Ord, V>(pairs: &'a [(K, V)], key: &K) -> Option<&'a V> {
+    let index = pairs.binary_search_by(|(k, _)| k.cmp(key)).ok()?;
+
+    Some(&pairs[index].1)
+}
+
+impl<K: Ord, V, const N: usize> Map<K, V> for Sorted<[(K, V); N]> {
+    fn get(&self, key: &K) -> Option<&V> {
+        binary_search(&self.0, key)
+    }
+}
+
+impl<K: Ord, V> Map<K, V> for Sorted<&[(K, V)]> {
+    fn get(&self, key: &K) -> Option<&V> {
+        binary_search(self.0, key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_get() {
+        let map = Sorted::new([('z', 1), ('a', 2), ('\u{F03A}', 3), ('<', 4)]);
+
+        assert_eq!(map.get(&'a'), Some(&2));
+        assert_eq!(map.get(&'z'), Some(&1));
+        assert_eq!(map.get(&'\u{F03A}'), Some(&3));
+        assert_eq!(map.get(&'<'), Some(&4));
+        assert_eq!(map.get(&'b'), None);
+    }
+
+    #[test]
+    fn test_from_sorted_slice() {
+        const PAIRS: &[(char, char)] = &[('"', '\''), ('*', '+'), ('?', '!')];
+        let map = Sorted::from_sorted(PAIRS);
+
+        assert_eq!(map.get(&'*'), Some(&'+'));
+        assert_eq!(map.get(&'?'), Some(&'!'));
+        assert_eq!(map.get(&'!'), None);
+    }
+}